@@ -0,0 +1,89 @@
+use std::{
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+};
+
+use aoc_2023::{inputs, Day, YEARS};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+// Benchmarks every day's run on its input and samples, plus parsing and each part on
+// their own. Inputs are read before measuring. Samples are often only for one part, so
+// only the parts that solve a file are benchmarked on it (and run if both do).
+// Run one day with e.g. `cargo bench -- 2023/day12/`.
+fn days(c: &mut Criterion) {
+    for year in YEARS {
+        for (i, d) in year.days.iter().enumerate() {
+            let day = i + 1;
+            let mut group = c.benchmark_group(format!("{}/day{}", year.year, day));
+            for filename in inputs::puzzle_files(year.year, day).unwrap_or_default() {
+                let input = match inputs::read(year.year, day, &filename) {
+                    Ok(input) => input,
+                    Err(e) => {
+                        eprintln!("Skipping {}/day{} {}: {}", year.year, day, filename, e);
+                        continue;
+                    }
+                };
+                let parts = solved_parts(d, &input);
+                if parts.is_empty() {
+                    eprintln!(
+                        "Skipping {}/day{} {}: neither part solves it",
+                        year.year, day, filename
+                    );
+                    continue;
+                }
+                bench_file(&mut group, d, &filename, &input, &parts);
+            }
+            group.finish();
+        }
+    }
+}
+
+fn bench_file<M: criterion::measurement::Measurement>(
+    group: &mut criterion::BenchmarkGroup<M>,
+    d: &Day,
+    filename: &str,
+    input: &str,
+    parts: &[usize],
+) {
+    if parts.len() == 2 {
+        group.bench_with_input(BenchmarkId::new("run", filename), input, |b, input| {
+            b.iter(|| (d.run)(black_box(input)))
+        });
+    }
+    // Parsing on its own includes dropping the parsed input
+    group.bench_with_input(BenchmarkId::new("parse", filename), input, |b, input| {
+        b.iter(|| (d.with_parsed)(black_box(input), &mut |_| {}))
+    });
+    let _ = (d.with_parsed)(input, &mut |solve| {
+        for &part in parts {
+            group.bench_function(BenchmarkId::new(format!("part{}", part), filename), |b| {
+                b.iter(|| solve(black_box(part)))
+            });
+        }
+    });
+}
+
+fn solved_parts(d: &Day, input: &str) -> Vec<usize> {
+    let mut parts = vec![];
+    quietly(|| {
+        (d.with_parsed)(input, &mut |solve| {
+            parts = [1, 2]
+                .into_iter()
+                .filter(|&part| quietly(|| solve(part)).is_some_and(|r| r.is_ok()))
+                .collect();
+        })
+    });
+    parts
+}
+
+// None if f panicked, without printing the panic
+fn quietly<T>(f: impl FnOnce() -> T) -> Option<T> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.ok()
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,181 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+    path::{Path, PathBuf},
+};
+
+// Generates a test for every input and sample file under inputs/<year>/<day>/ that
+// src/runner.rs includes, and with the embed-inputs feature the table of those files
+// and the answers manifests that src/inputs.rs includes, so they're baked into the
+// binary. Directories straight under inputs/ that aren't years are the old
+// inputs/<day>/ layout, for 2023.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=inputs");
+    println!("cargo:rerun-if-changed=src");
+
+    let root = Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).to_path_buf();
+    let files = input_files(&root.join("inputs"));
+    write_file_tests(&files, &root.join("src"));
+    if std::env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        write_embedded(&files);
+    }
+}
+
+type File = (u32, usize, String, PathBuf);
+
+fn input_files(root: &Path) -> Vec<File> {
+    let (years, legacy): (Vec<_>, Vec<_>) = numbered_dirs(root)
+        .into_iter()
+        .partition(|(n, _)| *n >= 1000);
+    let mut files = vec![];
+    for (year, dir) in years {
+        for (day, day_dir) in numbered_dirs(&dir) {
+            add_files(&mut files, year as u32, day, &day_dir);
+        }
+    }
+    // The new layout wins over the old one
+    let mut legacy_files = vec![];
+    for (day, dir) in legacy {
+        add_files(&mut legacy_files, 2023, day, &dir);
+    }
+    legacy_files.retain(|l| !files.iter().any(|f| (f.0, f.1, &f.2) == (l.0, l.1, &l.2)));
+    files.extend(legacy_files);
+    files.sort();
+    files
+}
+
+fn numbered_dirs(dir: &Path) -> Vec<(usize, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return vec![];
+    };
+    entries
+        .map(|e| e.unwrap().path())
+        .filter(|p| p.is_dir())
+        .filter_map(|p| Some((p.file_name()?.to_str()?.parse().ok()?, p)))
+        .collect()
+}
+
+fn add_files(files: &mut Vec<File>, year: u32, day: usize, dir: &Path) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_file() {
+            let filename = path.file_name().unwrap().to_str().unwrap().to_string();
+            files.push((year, day, filename, path));
+        }
+    }
+}
+
+// One test per input.txt and sample*.txt file, and per file in the day's answers
+// manifest (so they fail if it's missing), for the days with a solution
+fn write_file_tests(files: &[File], src: &Path) {
+    let mut tests: BTreeMap<(u32, usize), BTreeSet<String>> = BTreeMap::new();
+    for (year, day, filename, path) in files {
+        let names = tests.entry((*year, *day)).or_default();
+        if is_puzzle_file(filename) {
+            names.insert(filename.clone());
+        } else if filename == "answers.toml" {
+            // Only the tables' names are needed, e.g. ["sample.txt"]
+            let manifest = std::fs::read_to_string(path).unwrap();
+            match manifest.parse::<toml::Table>() {
+                Ok(table) => names.extend(table.keys().cloned()),
+                // The tests report it, as loading the manifest fails
+                Err(e) => println!("cargo:warning={}: {}", path.display(), e),
+            }
+        }
+    }
+
+    let mut out = String::new();
+    let mut current_year = None;
+    for ((year, day), names) in tests {
+        let source = src
+            .join(format!("year{}", year))
+            .join(format!("day{}.rs", day));
+        if names.is_empty() || !source.exists() {
+            continue;
+        }
+        if current_year != Some(year) {
+            if current_year.is_some() {
+                out.push_str("}\n");
+            }
+            writeln!(out, "mod year{} {{", year).unwrap();
+            current_year = Some(year);
+        }
+        writeln!(out, "    mod day{} {{", day).unwrap();
+        let mut test_names = BTreeSet::new();
+        for name in names {
+            // Names that only differ in punctuation get a suffix, e.g. sample-2.txt and
+            // sample_2.txt are tested by sample_2() and sample_2_2()
+            let base = test_name(&name);
+            let mut test = base.clone();
+            for n in 2.. {
+                if !test_names.contains(&test) {
+                    break;
+                }
+                test = format!("{}_{}", base, n);
+            }
+            test_names.insert(test.clone());
+            writeln!(
+                out,
+                "        #[test]\n        fn {}() -> color_eyre::Result<()> {{\n            \
+                 crate::runner::test_file({}, {}, {:?})\n        }}",
+                test, year, day, name
+            )
+            .unwrap();
+        }
+        out.push_str("    }\n");
+    }
+    if current_year.is_some() {
+        out.push_str("}\n");
+    }
+    write("file_tests.rs", out);
+}
+
+fn is_puzzle_file(filename: &str) -> bool {
+    filename == "input.txt" || (filename.starts_with("sample") && filename.ends_with(".txt"))
+}
+
+// sample_part2.txt is tested by sample_part2()
+fn test_name(filename: &str) -> String {
+    let name: String = filename
+        .trim_end_matches(".txt")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("file_{}", name)
+    } else {
+        name
+    }
+}
+
+// Other files (e.g. the submission history) are only ever read from disk
+fn write_embedded(files: &[File]) {
+    let mut entries = String::new();
+    let embedded = files
+        .iter()
+        .filter(|(_, _, filename, _)| is_puzzle_file(filename) || filename == "answers.toml");
+    for (year, day, filename, path) in embedded {
+        writeln!(
+            entries,
+            "    ({}, {}, {:?}, include_str!({:?})),",
+            year,
+            day,
+            filename,
+            path.display().to_string()
+        )
+        .unwrap();
+    }
+    write(
+        "embedded_inputs.rs",
+        format!(
+            "static EMBEDDED: &[(u32, usize, &str, &str)] = &[\n{}];\n",
+            entries
+        ),
+    );
+}
+
+fn write(filename: &str, contents: String) {
+    let dest = Path::new(&std::env::var("OUT_DIR").unwrap()).join(filename);
+    std::fs::write(dest, contents).unwrap();
+}
@@ -0,0 +1,258 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use color_eyre::Result;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::inputs;
+
+// One part's answer. Serialized as a bare number or string, with None as null (and
+// so never written to a manifest). TOML integers are 64-bit, so integers outside
+// i64's range are written as strings of digits, which are read back as integers.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    // The puzzle has no answer for this part, e.g. day 25 part 2
+    #[default]
+    None,
+}
+
+impl Answer {
+    // Answers worth recording or checking
+    pub fn known(self) -> Option<Self> {
+        match self {
+            Answer::None => None,
+            a => Some(a),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => write!(f, "-"),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => match i64::try_from(*n) {
+                Ok(n) => serializer.serialize_i64(n),
+                Err(_) => serializer.serialize_str(&n.to_string()),
+            },
+            Answer::Text(s) => serializer.serialize_str(s),
+            Answer::None => serializer.serialize_unit(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl de::Visitor<'_> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "an integer, a string or null")
+    }
+
+    fn visit_i64<E>(self, n: i64) -> Result<Answer, E> {
+        Ok(n.into())
+    }
+
+    fn visit_u64<E>(self, n: u64) -> Result<Answer, E> {
+        Ok(n.into())
+    }
+
+    fn visit_i128<E>(self, n: i128) -> Result<Answer, E> {
+        Ok(n.into())
+    }
+
+    // Only integers too large for i64 are written as strings
+    fn visit_str<E>(self, s: &str) -> Result<Answer, E> {
+        match s.parse::<i128>() {
+            Ok(n) if i64::try_from(n).is_err() && n.to_string() == s => Ok(Answer::Int(n)),
+            _ => Ok(Answer::Text(s.to_string())),
+        }
+    }
+
+    fn visit_unit<E>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+
+    fn visit_none<E>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(n: i128) -> Self {
+        Answer::Int(n)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Int(n.into())
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Int(n.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Int(n.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Int(n.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::from(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<Answer>,
+}
+
+// Expected answers for each input file of one day, kept in
+// inputs/<year>/<day>/answers.toml:
+//
+// ["input.txt"]
+// part1 = 55816
+// part2 = 54980
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Manifest {
+    files: BTreeMap<String, Answers>,
+}
+
+impl Manifest {
+    // A missing manifest just means we don't know any answers yet
+    pub fn load(year: u32, day: usize) -> Result<Self> {
+        match inputs::read_optional(year, day, "answers.toml")? {
+            Some(s) => Self::parse(&s),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(toml::from_str(input)?)
+    }
+
+    pub fn save(&self, year: u32, day: usize) -> Result<()> {
+        inputs::write(year, day, "answers.toml", &toml::to_string(self)?)
+    }
+
+    pub fn get(&self, filename: &str) -> Answers {
+        self.files.get(filename).cloned().unwrap_or_default()
+    }
+
+    pub fn set(&mut self, filename: &str, answers: Answers) {
+        self.files.insert(filename.to_string(), answers);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_partial_answers() -> Result<()> {
+        let manifest = Manifest::parse(
+            r#"
+            ["input.txt"]
+            part1 = 55816
+            part2 = 54980
+
+            ["sample.txt"]
+            part2 = 281
+            "#,
+        )?;
+        assert_eq!(Some(Answer::Int(55816)), manifest.get("input.txt").part1);
+        assert_eq!(Some(Answer::Int(54980)), manifest.get("input.txt").part2);
+        assert_eq!(None, manifest.get("sample.txt").part1);
+        assert_eq!(Some(Answer::Int(281)), manifest.get("sample.txt").part2);
+        assert_eq!(Answers::default(), manifest.get("other.txt"));
+        Ok(())
+    }
+
+    #[test]
+    fn round_trips() -> Result<()> {
+        let mut manifest = Manifest::default();
+        manifest.set(
+            "input.txt",
+            Answers {
+                part1: Some(Answer::Int(14935034899483)),
+                part2: None,
+            },
+        );
+        manifest.set(
+            "sample.txt",
+            Answers {
+                part1: Some(Answer::Int(-2)),
+                part2: Some(Answer::Text("EHPZPJGL".to_string())),
+            },
+        );
+        manifest.set(
+            "sample_2.txt",
+            Answers {
+                part1: Some(Answer::from(u64::MAX)),
+                part2: Some(Answer::Text("123".to_string())),
+            },
+        );
+        let reparsed = Manifest::parse(&toml::to_string(&manifest)?)?;
+        for filename in ["input.txt", "sample.txt", "sample_2.txt"] {
+            assert_eq!(manifest.get(filename), reparsed.get(filename));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn keeps_answers_beyond_i64() -> Result<()> {
+        let answer = Answer::from(u64::MAX);
+        assert_eq!("18446744073709551615", answer.to_string());
+        let json = serde_json::to_string(&answer)?;
+        assert_eq!("\"18446744073709551615\"", json);
+        assert_eq!(answer, serde_json::from_str(&json)?);
+        assert_eq!(answer, serde_json::from_str("18446744073709551615")?);
+        assert_eq!(Answer::Int(-3), serde_json::from_str("-3")?);
+        assert_eq!(Answer::None, serde_json::from_str("null")?);
+        Ok(())
+    }
+}
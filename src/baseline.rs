@@ -0,0 +1,287 @@
+use std::{path::Path, time::Duration};
+
+use color_eyre::{eyre::WrapErr, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::bench::{DayStats, Stats};
+use crate::report::{format_runtime_duration, TIME_BUDGET};
+
+// Saved benchmark results to compare later runs against. Budgets aren't written by
+// the benchmark itself, but can be added to the file by hand and are kept when the
+// baseline is re-saved.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_budget_us: Option<f64>,
+    pub days: Vec<BaselineDay>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaselineDay {
+    pub day: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub budget_us: Option<f64>,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+impl Baseline {
+    pub fn load(path: &str) -> Result<Self> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+
+    // Saves new results over those for the same days in an existing baseline at the
+    // same path, keeping its other days and any budgets
+    pub fn save(path: &str, results: &[DayStats]) -> Result<()> {
+        let mut baseline = if Path::new(path).exists() {
+            Self::load(path).wrap_err_with(|| format!("Not overwriting {}", path))?
+        } else {
+            Self::default()
+        };
+        baseline.merge(results);
+        std::fs::write(path, serde_json::to_string_pretty(&baseline)?)?;
+        Ok(())
+    }
+
+    fn merge(&mut self, results: &[DayStats]) {
+        for d in results {
+            match self.days.iter_mut().find(|b| b.day == d.day) {
+                Some(b) => b.stats = d.stats.clone(),
+                None => self.days.push(BaselineDay {
+                    day: d.day,
+                    budget_us: None,
+                    stats: d.stats.clone(),
+                }),
+            }
+        }
+        self.days.sort_by_key(|d| d.day);
+    }
+
+    fn day(&self, day: usize) -> Option<&BaselineDay> {
+        self.days.iter().find(|d| d.day == day)
+    }
+
+    fn total_budget_us(&self) -> f64 {
+        self.total_budget_us
+            .unwrap_or(TIME_BUDGET.as_secs_f64() * 1e6)
+    }
+
+    pub fn compare(&self, results: &[DayStats], max_regression_pct: f64) -> Comparison {
+        let days = results
+            .iter()
+            .map(|d| {
+                let baseline = self.day(d.day);
+                let change_pct = baseline
+                    .map(|b| (d.stats.median_us - b.stats.median_us) / b.stats.median_us * 100.0);
+                DayComparison {
+                    day: d.day,
+                    median_us: d.stats.median_us,
+                    baseline_median_us: baseline.map(|b| b.stats.median_us),
+                    change_pct,
+                    regressed: change_pct.is_some_and(|c| c > max_regression_pct),
+                    over_budget: baseline
+                        .and_then(|b| b.budget_us)
+                        .is_some_and(|budget| d.stats.median_us > budget),
+                }
+            })
+            .collect();
+        let total_us = results.iter().map(|d| d.stats.median_us).sum();
+        Comparison {
+            days,
+            total_us,
+            total_budget_us: self.total_budget_us(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DayComparison {
+    pub day: usize,
+    pub median_us: f64,
+    pub baseline_median_us: Option<f64>,
+    pub change_pct: Option<f64>,
+    pub regressed: bool,
+    pub over_budget: bool,
+}
+
+#[derive(Debug)]
+pub struct Comparison {
+    pub days: Vec<DayComparison>,
+    pub total_us: f64,
+    pub total_budget_us: f64,
+}
+
+impl Comparison {
+    pub fn failures(&self) -> usize {
+        self.days
+            .iter()
+            .filter(|d| d.regressed || d.over_budget)
+            .count()
+            + self.total_over_budget() as usize
+    }
+
+    fn total_over_budget(&self) -> bool {
+        self.total_us > self.total_budget_us
+    }
+
+    pub fn print_text(&self) {
+        let fmt = |us: f64| format_runtime_duration(&Duration::from_secs_f64(us / 1e6));
+        for d in &self.days {
+            let mut line = match (d.baseline_median_us, d.change_pct) {
+                (Some(b), Some(c)) => format!(
+                    "Day {}:\tmedian {} vs {} baseline ({:+.1}%)",
+                    d.day,
+                    fmt(d.median_us),
+                    fmt(b),
+                    c
+                ),
+                _ => format!("Day {}:\tmedian {} (no baseline)", d.day, fmt(d.median_us)),
+            };
+            if d.regressed {
+                line.push_str(" REGRESSED");
+            }
+            if d.over_budget {
+                line.push_str(" OVER BUDGET");
+            }
+            println!("{}", line);
+        }
+        println!(
+            "Total of medians: {} of {} budget{}",
+            fmt(self.total_us),
+            fmt(self.total_budget_us),
+            if self.total_over_budget() {
+                " OVER BUDGET"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_us: f64) -> Stats {
+        Stats {
+            samples: 10,
+            outliers: 0,
+            min_us: median_us,
+            median_us,
+            p95_us: median_us,
+            mean_us: median_us,
+            stddev_us: 0.0,
+            max_us: median_us,
+        }
+    }
+
+    fn baseline() -> Baseline {
+        Baseline {
+            total_budget_us: None,
+            days: vec![
+                BaselineDay {
+                    day: 1,
+                    budget_us: None,
+                    stats: stats(100.0),
+                },
+                BaselineDay {
+                    day: 2,
+                    budget_us: Some(1000.0),
+                    stats: stats(500.0),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn flags_regressions_over_threshold() {
+        let results = vec![
+            DayStats {
+                day: 1,
+                stats: stats(109.0),
+            },
+            DayStats {
+                day: 2,
+                stats: stats(600.0),
+            },
+            DayStats {
+                day: 3,
+                stats: stats(10.0),
+            },
+        ];
+        let comparison = baseline().compare(&results, 10.0);
+        assert!(!comparison.days[0].regressed);
+        assert!(comparison.days[1].regressed);
+        assert!(!comparison.days[1].over_budget);
+        assert_eq!(None, comparison.days[2].change_pct);
+        assert_eq!(1, comparison.failures());
+    }
+
+    #[test]
+    fn enforces_budgets() {
+        let results = vec![DayStats {
+            day: 2,
+            stats: stats(1100.0),
+        }];
+        let comparison = baseline().compare(&results, 1000.0);
+        assert!(!comparison.days[0].regressed);
+        assert!(comparison.days[0].over_budget);
+        assert_eq!(1, comparison.failures());
+
+        let results = vec![DayStats {
+            day: 3,
+            stats: stats(2_000_000.0),
+        }];
+        assert_eq!(1, baseline().compare(&results, 10.0).failures());
+    }
+
+    #[test]
+    fn merges_results_into_previous_days() {
+        let mut baseline = baseline();
+        baseline.merge(&[
+            DayStats {
+                day: 3,
+                stats: stats(10.0),
+            },
+            DayStats {
+                day: 2,
+                stats: stats(400.0),
+            },
+        ]);
+        let days: Vec<_> = baseline
+            .days
+            .iter()
+            .map(|d| (d.day, d.budget_us, d.stats.median_us))
+            .collect();
+        assert_eq!(
+            vec![(1, None, 100.0), (2, Some(1000.0), 400.0), (3, None, 10.0)],
+            days
+        );
+    }
+
+    #[test]
+    fn wont_overwrite_an_unreadable_baseline() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.json", std::process::id()));
+        std::fs::write(&path, "{ not json")?;
+        let path = path.to_str().unwrap();
+        assert!(Baseline::save(path, &[]).is_err());
+        assert_eq!("{ not json", std::fs::read_to_string(path)?);
+        std::fs::remove_file(path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn reads_hand_written_budgets() -> Result<()> {
+        let baseline: Baseline = serde_json::from_str(
+            r#"{
+                "total_budget_us": 500000,
+                "days": [{"day": 14, "budget_us": 15000, "samples": 10, "outliers": 0,
+                          "min_us": 1.0, "median_us": 2.0, "p95_us": 3.0, "mean_us": 2.0,
+                          "stddev_us": 0.5, "max_us": 3.0}]
+            }"#,
+        )?;
+        assert_eq!(500000.0, baseline.total_budget_us());
+        assert_eq!(Some(15000.0), baseline.day(14).unwrap().budget_us);
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::inputs;
+use crate::report::format_runtime_duration;
+use crate::runner::normal_day;
+use crate::solution::Day;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+// All times are in microseconds, after outliers have been rejected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub outliers: usize,
+    pub min_us: f64,
+    pub median_us: f64,
+    pub p95_us: f64,
+    pub mean_us: f64,
+    pub stddev_us: f64,
+    pub max_us: f64,
+}
+
+impl Stats {
+    // Samples outside Tukey's fences (1.5 * IQR beyond the quartiles) are dropped
+    // before computing the statistics, so one-off hiccups don't skew the results.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        let mut us: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1e6).collect();
+        us.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let (q1, q3) = (percentile(&us, 25.0), percentile(&us, 75.0));
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        let kept: Vec<f64> = us
+            .iter()
+            .copied()
+            .filter(|t| *t >= low && *t <= high)
+            .collect();
+
+        let n = kept.len() as f64;
+        let mean = kept.iter().sum::<f64>() / n;
+        let variance = kept.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / n;
+        Self {
+            samples: kept.len(),
+            outliers: us.len() - kept.len(),
+            min_us: kept[0],
+            median_us: percentile(&kept, 50.0),
+            p95_us: percentile(&kept, 95.0),
+            mean_us: mean,
+            stddev_us: variance.sqrt(),
+            max_us: kept[kept.len() - 1],
+        }
+    }
+
+    pub fn print_text(&self, day: usize) {
+        let fmt = |us: f64| format_runtime_duration(&Duration::from_secs_f64(us / 1e6));
+        println!(
+            "Day {}:\tmin {}\tmedian {}\tp95 {}\tstddev {}\t({} samples, {} outliers)",
+            day,
+            fmt(self.min_us),
+            fmt(self.median_us),
+            fmt(self.p95_us),
+            fmt(self.stddev_us),
+            self.samples,
+            self.outliers
+        );
+    }
+}
+
+// Linear interpolation between the closest ranks of sorted values
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - lo as f64)
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DayStats {
+    pub day: usize,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+pub fn to_csv(days: &[DayStats]) -> String {
+    let mut s =
+        String::from("day,samples,outliers,min_us,median_us,p95_us,mean_us,stddev_us,max_us\n");
+    for DayStats { day, stats } in days {
+        writeln!(
+            s,
+            "{},{},{},{:.1},{:.1},{:.1},{:.1},{:.1},{:.1}",
+            day,
+            stats.samples,
+            stats.outliers,
+            stats.min_us,
+            stats.median_us,
+            stats.p95_us,
+            stats.mean_us,
+            stats.stddev_us,
+            stats.max_us
+        )
+        .unwrap();
+    }
+    s
+}
+
+// Checks the day's answers once, then times only the solver on the already loaded input
+pub fn bench_day(d: &Day, year: u32, day: usize, options: BenchOptions) -> Result<Stats> {
+    normal_day(d, year, day)?;
+    let input = inputs::read(year, day, "input.txt")?;
+    for _ in 0..options.warmup {
+        (d.run)(&input)?;
+    }
+    let mut samples = Vec::with_capacity(options.iterations);
+    for _ in 0..options.iterations.max(1) {
+        let start = Instant::now();
+        (d.run)(&input)?;
+        samples.push(start.elapsed());
+    }
+    Ok(Stats::from_samples(&samples))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(v: &[u64]) -> Vec<Duration> {
+        v.iter().map(|us| Duration::from_micros(*us)).collect()
+    }
+
+    #[test]
+    fn computes_percentiles() {
+        let stats = Stats::from_samples(&micros(&[10, 20, 30, 40, 50]));
+        assert_eq!(0, stats.outliers);
+        assert_eq!(10.0, stats.min_us);
+        assert_eq!(30.0, stats.median_us);
+        assert_eq!(48.0, stats.p95_us);
+        assert_eq!(30.0, stats.mean_us);
+        assert!((stats.stddev_us - 200f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = Stats::from_samples(&micros(&[100, 101, 99, 100, 102, 98, 100, 5000]));
+        assert_eq!(1, stats.outliers);
+        assert_eq!(7, stats.samples);
+        assert_eq!(102.0, stats.max_us);
+        assert_eq!(100.0, stats.median_us);
+    }
+
+    #[test]
+    fn single_sample() {
+        let stats = Stats::from_samples(&micros(&[42]));
+        assert_eq!(42.0, stats.min_us);
+        assert_eq!(42.0, stats.p95_us);
+        assert_eq!(0.0, stats.stddev_us);
+    }
+}
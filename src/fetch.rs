@@ -0,0 +1,258 @@
+use std::time::Duration;
+
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+
+use crate::inputs;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Downloads inputs (and samples) from Advent of Code, or anything serving the same
+// paths: <base>/<year>/day/<day>/input and the puzzle page at <base>/<year>/day/<day>
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(concat!(
+                    env!("CARGO_PKG_NAME"),
+                    "/",
+                    env!("CARGO_PKG_VERSION")
+                ))
+                .build(),
+        }
+    }
+
+    // The session comes from $AOC_SESSION, falling back to a .session file in the
+    // working directory (the value of the "session" cookie when logged in)
+    pub fn from_env(base_url: Option<&str>) -> Result<Self> {
+        let base_url = base_url
+            .map(str::to_string)
+            .or_else(|| std::env::var("AOC_BASE_URL").ok())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => std::fs::read_to_string(".session")
+                .wrap_err("No session token: set AOC_SESSION or write it to .session")?,
+        };
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn request(&self, method: &str, path: &str) -> (String, ureq::Request) {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session));
+        (url, request)
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let (url, request) = self.request("GET", path);
+        body(&url, request.call())
+    }
+
+    pub fn input(&self, year: u32, day: usize) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    // The first example on the puzzle page, if there is one
+    pub fn sample(&self, year: u32, day: usize) -> Result<Option<String>> {
+        Ok(extract_sample(
+            &self.get(&format!("/{}/day/{}", year, day))?,
+        ))
+    }
+
+    // Posts an answer, returning the page that says whether it was right
+    pub fn submit(&self, year: u32, day: usize, part: usize, answer: &str) -> Result<String> {
+        let (url, request) = self.request("POST", &format!("/{}/day/{}/answer", year, day));
+        body(
+            &url,
+            request.send_form(&[("level", &part.to_string()), ("answer", answer)]),
+        )
+    }
+}
+
+fn body(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(404, _)) => Err(eyre!(
+            "Fetching {}: not found (is the puzzle out yet?)",
+            url
+        )),
+        Err(ureq::Error::Status(code, _)) => Err(eyre!(
+            "Fetching {}: status {} (is the session token valid?)",
+            url,
+            code
+        )),
+        Err(e) => Err(e).wrap_err_with(|| format!("Fetching {}", url)),
+    }
+}
+
+// Downloads the day's input (and sample) into the inputs directory, unless they're
+// already there
+pub fn fetch_day(client: &Client, year: u32, day: usize, sample: bool, force: bool) -> Result<()> {
+    // Embedded copies don't count, as the point is to have the files on disk
+    let cached =
+        |filename| !force && inputs::read_from_disk(year, day, filename).is_ok_and(|s| s.is_some());
+    if cached("input.txt") {
+        println!("Day {}: input.txt already fetched", day);
+    } else {
+        inputs::write(year, day, "input.txt", &client.input(year, day)?)?;
+        println!("Day {}: fetched input.txt", day);
+    }
+    if sample {
+        if cached("sample.txt") {
+            println!("Day {}: sample.txt already fetched", day);
+        } else if let Some(s) = client.sample(year, day)? {
+            inputs::write(year, day, "sample.txt", &s)?;
+            println!("Day {}: fetched sample.txt", day);
+        } else {
+            println!("Day {}: no sample found on the puzzle page", day);
+        }
+    }
+    Ok(())
+}
+
+// Puzzle pages put the examples in <pre><code> blocks
+fn extract_sample(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + html[start..].find("</code></pre>")?;
+    Some(unescape(&strip_tags(&html[start..end])))
+}
+
+// Examples sometimes highlight parts with <em>
+pub fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut in_tag = false;
+    for c in s.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
+
+pub fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// A stand-in for the Advent of Code server
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::JoinHandle,
+    };
+
+    // A request's line and headers, then its body
+    pub type Request = (Vec<String>, String);
+
+    // Serves one canned response per request, returning the requests received
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(&stream);
+                let head: Vec<String> = reader
+                    .by_ref()
+                    .lines()
+                    .map(|l| l.unwrap())
+                    .take_while(|l| !l.is_empty())
+                    .collect();
+                let length = head
+                    .iter()
+                    .find_map(|l| {
+                        l.to_lowercase()
+                            .strip_prefix("content-length: ")?
+                            .parse()
+                            .ok()
+                    })
+                    .unwrap_or(0);
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+                requests.push((head, String::from_utf8(request_body).unwrap()));
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock::serve;
+    use super::*;
+
+    #[test]
+    fn fetches_input_with_session() -> Result<()> {
+        let (base_url, server) = serve(vec![(200, "1abc2\npqr3stu8vwx\n")]);
+        let client = Client::new(&format!("{}/", base_url), "s3cret\n");
+        assert_eq!("1abc2\npqr3stu8vwx\n", client.input(2023, 1)?);
+
+        let requests = server.join().unwrap();
+        assert_eq!("GET /2023/day/1/input HTTP/1.1", requests[0].0[0]);
+        assert!(requests[0]
+            .0
+            .iter()
+            .any(|l| l.eq_ignore_ascii_case("cookie: session=s3cret")));
+        Ok(())
+    }
+
+    #[test]
+    fn reports_missing_puzzles() {
+        let (base_url, server) = serve(vec![(404, "Not found"), (400, "Bad session")]);
+        let client = Client::new(&base_url, "s3cret");
+        let e = client.input(2023, 26).unwrap_err().to_string();
+        assert!(e.contains("not found"), "{}", e);
+        let e = client.input(2023, 1).unwrap_err().to_string();
+        assert!(e.contains("status 400"), "{}", e);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn fetches_sample_from_puzzle_page() -> Result<()> {
+        let page = "<article><p>For example:</p>\
+                    <pre><code>Time:      7  15   30\n<em>Distance</em>:  9  40  200\n</code></pre>\
+                    <p>Then:</p><pre><code>a -&gt; b &amp; c\n</code></pre></article>";
+        let (base_url, server) = serve(vec![(200, page), (200, "<p>No examples</p>")]);
+        let client = Client::new(&base_url, "s3cret");
+        assert_eq!(
+            Some("Time:      7  15   30\nDistance:  9  40  200\n".to_string()),
+            client.sample(2023, 6)?
+        );
+        assert_eq!(None, client.sample(2023, 7)?);
+        assert_eq!("a -> b & c\n", unescape("a -&gt; b &amp; c\n"));
+        assert_eq!("GET /2023/day/6 HTTP/1.1", server.join().unwrap()[0].0[0]);
+        Ok(())
+    }
+}
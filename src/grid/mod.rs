@@ -0,0 +1,599 @@
+use std::{
+    fmt::{Debug, Display},
+    ops::Add,
+};
+
+use color_eyre::{eyre::eyre, Result};
+
+mod render;
+mod search;
+
+pub use render::{Image, Overlay, Rgb};
+pub use search::Paths;
+
+// A position that may be off the grid, e.g. one step past its edge. y grows southwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, dir: Dir) -> Self {
+        self + dir.delta()
+    }
+
+    // As (x, y) to index a grid of the given dimensions with, if it's on the grid
+    pub fn in_bounds(self, dimensions: (usize, usize)) -> Option<(usize, usize)> {
+        let (x, y) = (usize::try_from(self.x).ok()?, usize::try_from(self.y).ok()?);
+        (x < dimensions.0 && y < dimensions.1).then_some((x, y))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x as isize, y as isize)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+// Clockwise from north, so each can be used as a bit index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::North, Dir::East, Dir::South, Dir::West];
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir::North => Point::new(0, -1),
+            Dir::East => Point::new(1, 0),
+            Dir::South => Point::new(0, 1),
+            Dir::West => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Dir::ALL[(self as usize + 2) % 4]
+    }
+}
+
+// The eight points around one, clockwise from north
+const AROUND: [Point; 8] = [
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+    Point::new(-1, -1),
+];
+
+// How a view's positions map onto its grid. Rotations are clockwise, and flipping
+// horizontally mirrors left and right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    RotateClockwise,
+    Rotate180,
+    RotateCounterClockwise,
+    FlipHorizontal,
+    FlipVertical,
+    Transpose,
+    AntiTranspose,
+}
+
+impl Transform {
+    // The dimensions of a view of a grid with the given dimensions
+    pub fn dimensions(self, (dim_x, dim_y): (usize, usize)) -> (usize, usize) {
+        if self.swaps_axes() {
+            (dim_y, dim_x)
+        } else {
+            (dim_x, dim_y)
+        }
+    }
+
+    fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Transform::RotateClockwise
+                | Transform::RotateCounterClockwise
+                | Transform::Transpose
+                | Transform::AntiTranspose
+        )
+    }
+
+    // Where (0, 0) of the view is in a grid's elements, and how far a step in x and
+    // in y moves through them, for a grid of the given dimensions
+    fn layout(self, (dim_x, dim_y): (usize, usize)) -> (usize, isize, isize) {
+        let (w, last_row, last_col) = (
+            dim_x as isize,
+            dim_x * dim_y.saturating_sub(1),
+            dim_x.saturating_sub(1),
+        );
+        match self {
+            Transform::Identity => (0, 1, w),
+            Transform::RotateClockwise => (last_row, -w, 1),
+            Transform::Rotate180 => (last_row + last_col, -1, -w),
+            Transform::RotateCounterClockwise => (last_col, w, -1),
+            Transform::FlipHorizontal => (last_col, -1, w),
+            Transform::FlipVertical => (last_row, 1, -w),
+            Transform::Transpose => (0, w, 1),
+            Transform::AntiTranspose => (last_row + last_col, -w, -1),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub dimensions: (usize, usize), // cols, rows
+    grid: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(default_elem: T, dim_x: usize, dim_y: usize) -> Self
+    where
+        T: Clone,
+    {
+        let grid = vec![default_elem; dim_x * dim_y];
+        let dimensions = (dim_x, dim_y);
+        Self { dimensions, grid }
+    }
+
+    pub fn at(&self, x: usize, y: usize) -> &T {
+        debug_assert!(x < self.dimensions.0, "x {} out of bounds", x);
+        debug_assert!(y < self.dimensions.1, "y {} out of bounds", y);
+        &self.grid[y * self.dimensions.0 + x]
+    }
+
+    pub fn at_mut(&mut self, x: usize, y: usize) -> &mut T {
+        &mut self.grid[y * self.dimensions.0 + x]
+    }
+
+    // None off the grid
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = Point::new(x, y).in_bounds(self.dimensions)?;
+        Some(self.at(x, y))
+    }
+
+    // The position one step away in the direction, if it's on the grid
+    pub fn step(&self, x: usize, y: usize, dir: Dir) -> Option<(usize, usize)> {
+        Point::from((x, y)).step(dir).in_bounds(self.dimensions)
+    }
+
+    // The orthogonal neighbours that are on the grid, with the direction to each
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (Dir, (usize, usize))> {
+        let dimensions = self.dimensions;
+        Dir::ALL.into_iter().filter_map(move |dir| {
+            let neighbor = Point::from((x, y)).step(dir).in_bounds(dimensions)?;
+            Some((dir, neighbor))
+        })
+    }
+
+    // The orthogonal and diagonal neighbours that are on the grid
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let dimensions = self.dimensions;
+        AROUND
+            .into_iter()
+            .filter_map(move |delta| (Point::from((x, y)) + delta).in_bounds(dimensions))
+    }
+
+    // Panics on input that isn't a grid, see try_parse
+    pub fn parse(input: &str, parse_char: impl Fn(char) -> T) -> Grid<T> {
+        Self::try_parse(input, parse_char).unwrap()
+    }
+
+    // One element per character, with a row per line (\n or \r\n). Every row has to be
+    // as long as the first; trailing blank lines are ignored.
+    pub fn try_parse(input: &str, parse_char: impl Fn(char) -> T) -> Result<Grid<T>> {
+        Self::try_parse_with(input, |c| Some(parse_char(c)))
+    }
+
+    // Like try_parse, for grids where some characters aren't valid (parse_char's None)
+    pub fn try_parse_with(input: &str, parse_char: impl Fn(char) -> Option<T>) -> Result<Grid<T>> {
+        let mut grid = vec![];
+        let mut dim_x = None;
+        let mut dim_y = 0;
+        for line in input.trim_end_matches(['\r', '\n']).lines() {
+            let row_start = grid.len();
+            for (x, c) in line.chars().enumerate() {
+                let elem = parse_char(c)
+                    .ok_or_else(|| eyre!("Unexpected {:?} at ({}, {})", c, x, dim_y))?;
+                grid.push(elem);
+            }
+            let len = grid.len() - row_start;
+            match dim_x {
+                None => dim_x = Some(len),
+                Some(dim_x) if len != dim_x => {
+                    return Err(eyre!(
+                        "Row {} has {} columns, expected {}",
+                        dim_y + 1,
+                        len,
+                        dim_x
+                    ))
+                }
+                _ => {}
+            }
+            dim_y += 1;
+        }
+        match dim_x {
+            Some(dim_x) if dim_x > 0 => Ok(Grid {
+                dimensions: (dim_x, dim_y),
+                grid,
+            }),
+            _ => Err(eyre!("Empty grid")),
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        let start = y * self.dimensions.0;
+        &self.grid[start..start + self.dimensions.0]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.grid.chunks(self.dimensions.0)
+    }
+
+    // Top to bottom
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.grid.iter().skip(x).step_by(self.dimensions.0)
+    }
+
+    // The first position (in reading order) whose element matches, e.g. the start
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        let i = self.grid.iter().position(f)?;
+        Some((i % self.dimensions.0, i / self.dimensions.0))
+    }
+
+    // Rows become columns
+    pub fn transposed(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(Transform::Transpose).to_grid()
+    }
+
+    // A quarter turn clockwise, so the left column becomes the top row
+    pub fn rotated_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(Transform::RotateClockwise).to_grid()
+    }
+
+    // The grid as if it were rotated, flipped or transposed, without copying it
+    pub fn view(&self, transform: Transform) -> View<'_, T> {
+        View {
+            layout: Layout::new(self, transform),
+            grid: self,
+        }
+    }
+
+    pub fn view_mut(&mut self, transform: Transform) -> ViewMut<'_, T> {
+        ViewMut {
+            layout: Layout::new(self, transform),
+            grid: self,
+        }
+    }
+
+    // As if the grid were repeated forever in every direction
+    pub fn at_tiled(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.dimensions.0 as isize) as usize;
+        let y = y.rem_euclid(self.dimensions.1 as isize) as usize;
+        self.at(x, y)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.grid.iter()
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            dimensions: self.dimensions,
+            grid: self.grid.iter().map(f).collect(),
+        }
+    }
+
+    pub fn iter_pts(&self, mut f: impl FnMut(usize, usize, &T)) {
+        for y in 0..self.dimensions.1 {
+            for x in 0..self.dimensions.0 {
+                f(x, y, self.at(x, y));
+            }
+        }
+    }
+
+    #[allow(unused)]
+    pub fn to_string(&self, format_elem: impl Fn(&T) -> String) -> String {
+        let mut s = String::new();
+        for y in 0..self.dimensions.1 {
+            for x in 0..self.dimensions.0 {
+                s.push_str(&format_elem(self.at(x, y)));
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+impl<T> Debug for Grid<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.dimensions.1 {
+            for c in 0..self.dimensions.0 {
+                write!(f, "{:?}", self.at(c, row))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.dimensions.1 {
+            for c in 0..self.dimensions.0 {
+                write!(f, "{}", self.at(c, row))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.dimensions.1 {
+            for c in 0..self.dimensions.0 {
+                write!(f, "{}", *self.at(c, row) as char)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Clone for Grid<T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dimensions: self.dimensions,
+            grid: self.grid.clone(),
+        }
+    }
+}
+
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    layout: Layout,
+}
+
+impl<T> View<'_, T> {
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.layout.dimensions
+    }
+
+    #[inline]
+    pub fn at(&self, x: usize, y: usize) -> &T {
+        &self.grid.grid[self.layout.index(x, y)]
+    }
+
+    // None off the view
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = Point::new(x, y).in_bounds(self.dimensions())?;
+        Some(self.at(x, y))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (dim_x, dim_y) = self.dimensions();
+        let grid = (0..dim_y)
+            .flat_map(|y| (0..dim_x).map(move |x| self.at(x, y).clone()))
+            .collect();
+        Grid {
+            dimensions: (dim_x, dim_y),
+            grid,
+        }
+    }
+}
+
+pub struct ViewMut<'a, T> {
+    grid: &'a mut Grid<T>,
+    layout: Layout,
+}
+
+impl<T> ViewMut<'_, T> {
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.layout.dimensions
+    }
+
+    #[inline]
+    pub fn at(&self, x: usize, y: usize) -> &T {
+        &self.grid.grid[self.layout.index(x, y)]
+    }
+
+    #[inline]
+    pub fn at_mut(&mut self, x: usize, y: usize) -> &mut T {
+        &mut self.grid.grid[self.layout.index(x, y)]
+    }
+}
+
+// Worked out once per view, so indexing doesn't depend on the transform
+#[derive(Clone, Copy)]
+struct Layout {
+    dimensions: (usize, usize),
+    origin: usize,
+    stride_x: isize,
+    stride_y: isize,
+}
+
+impl Layout {
+    fn new<T>(grid: &Grid<T>, transform: Transform) -> Self {
+        let (origin, stride_x, stride_y) = transform.layout(grid.dimensions);
+        Self {
+            dimensions: transform.dimensions(grid.dimensions),
+            origin,
+            stride_x,
+            stride_y,
+        }
+    }
+
+    #[inline]
+    fn index(&self, x: usize, y: usize) -> usize {
+        debug_assert!(x < self.dimensions.0, "x {} out of bounds", x);
+        debug_assert!(y < self.dimensions.1, "y {} out of bounds", y);
+        self.origin
+            .wrapping_add_signed(x as isize * self.stride_x + y as isize * self.stride_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors_stay_on_the_grid() {
+        let grid = Grid::new(0u8, 3, 2);
+        assert_eq!(
+            vec![(Dir::East, (1, 0)), (Dir::South, (0, 1))],
+            grid.neighbors4(0, 0).collect::<Vec<_>>()
+        );
+        assert_eq!(3, grid.neighbors4(1, 1).count());
+        assert_eq!(
+            vec![(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)],
+            grid.neighbors8(1, 1).collect::<Vec<_>>()
+        );
+        assert_eq!(Some((2, 1)), grid.step(1, 1, Dir::East));
+        assert_eq!(None, grid.step(2, 1, Dir::East));
+        assert_eq!(None, grid.step(0, 0, Dir::North));
+    }
+
+    #[test]
+    fn gets_signed_positions() {
+        let mut grid = Grid::new(0u8, 3, 2);
+        *grid.at_mut(2, 1) = 7;
+        assert_eq!(Some(&7), grid.get(2, 1));
+        assert_eq!(None, grid.get(-1, 0));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, 2));
+    }
+
+    #[test]
+    fn parses_rectangles() {
+        let grid = Grid::parse("#..\n..S\n", |c| c);
+        assert_eq!((3, 2), grid.dimensions);
+        assert_eq!((2, 3), Grid::parse("#.\n..\n.S", |c| c).dimensions);
+        assert_eq!(['.', '.', 'S'], grid.row(1));
+        assert_eq!(vec![&'#', &'.'], grid.col(0).collect::<Vec<_>>());
+        assert_eq!(2, grid.rows().count());
+        assert_eq!(Some((2, 1)), grid.find(|&c| c == 'S'));
+        assert_eq!(None, grid.find(|&c| c == 'X'));
+    }
+
+    #[test]
+    fn parses_crlf() -> Result<()> {
+        let grid = Grid::try_parse("ab\r\ncd\r\nef\r\n\r\n", |c| c)?;
+        assert_eq!((2, 3), grid.dimensions);
+        assert_eq!("ab\ncd\nef\n", format!("{}", grid));
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_ragged_and_empty_input() {
+        let err = Grid::try_parse("abc\nde\nfgh\n", |c| c).unwrap_err();
+        assert_eq!("Row 2 has 2 columns, expected 3", err.to_string());
+        assert!(Grid::try_parse("abc\n\nabc\n", |c| c).is_err());
+        assert!(Grid::try_parse("", |c| c).is_err());
+        assert!(Grid::try_parse("\n\n", |c| c).is_err());
+    }
+
+    #[test]
+    fn rejects_unexpected_characters() {
+        let digits = |input| Grid::try_parse_with(input, |c| c.to_digit(10));
+        assert_eq!((2, 2), digits("12\n34\n").unwrap().dimensions);
+        let err = digits("12\n3x\n").unwrap_err();
+        assert_eq!("Unexpected 'x' at (1, 1)", err.to_string());
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = Grid::parse("abc\ndef\n", |c| c);
+        assert_eq!("ad\nbe\ncf\n", format!("{}", grid.transposed()));
+        assert_eq!("da\neb\nfc\n", format!("{}", grid.rotated_clockwise()));
+        let full_turn = (0..4).fold(grid.clone(), |g, _| g.rotated_clockwise());
+        assert!(full_turn == grid);
+        assert!(grid.transposed().transposed() == grid);
+    }
+
+    #[test]
+    fn views_match_copies() {
+        let grid = Grid::parse("abc\ndef\n", |c| c);
+        let view = |transform| format!("{}", grid.view(transform).to_grid());
+        assert_eq!("abc\ndef\n", view(Transform::Identity));
+        assert_eq!("da\neb\nfc\n", view(Transform::RotateClockwise));
+        assert_eq!("fed\ncba\n", view(Transform::Rotate180));
+        assert_eq!("cf\nbe\nad\n", view(Transform::RotateCounterClockwise));
+        assert_eq!("cba\nfed\n", view(Transform::FlipHorizontal));
+        assert_eq!("def\nabc\n", view(Transform::FlipVertical));
+        assert_eq!("ad\nbe\ncf\n", view(Transform::Transpose));
+        assert_eq!("fc\neb\nda\n", view(Transform::AntiTranspose));
+        assert_eq!((2, 3), grid.view(Transform::Transpose).dimensions());
+        assert_eq!(None, grid.view(Transform::Transpose).get(2, 0));
+    }
+
+    #[test]
+    fn writes_through_views() {
+        let mut grid = Grid::parse("abc\ndef\n", |c| c);
+        let mut view = grid.view_mut(Transform::RotateCounterClockwise);
+        assert_eq!('c', *view.at(0, 0));
+        *view.at_mut(1, 2) = 'X';
+        assert_eq!("abc\nXef\n", format!("{}", grid));
+    }
+
+    #[test]
+    fn tiles() {
+        let grid = Grid::parse("ab\ncd\n", |c| c);
+        assert_eq!('a', *grid.at_tiled(2, -2));
+        assert_eq!('d', *grid.at_tiled(-1, -1));
+        assert_eq!('c', *grid.at_tiled(-4, 7));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Dir::East, Dir::North.turn_right());
+        assert_eq!(Dir::West, Dir::North.turn_left());
+        assert_eq!(Dir::North, Dir::South.reverse());
+        assert_eq!(Point::new(4, 2), Point::new(4, 3).step(Dir::North));
+        assert_eq!(None, Point::new(4, -1).in_bounds((5, 5)));
+    }
+}
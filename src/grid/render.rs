@@ -0,0 +1,200 @@
+use std::{fmt::Write, path::Path};
+
+use color_eyre::{eyre::eyre, Result};
+use rustc_hash::FxHashMap;
+
+use super::Grid;
+
+pub type Rgb = [u8; 3];
+
+// Colours for some positions, drawn over the grid, e.g. a path or the visited cells.
+// Later highlights win where they overlap.
+#[derive(Debug, Default, Clone)]
+pub struct Overlay {
+    cells: FxHashMap<(usize, usize), Rgb>,
+}
+
+impl Overlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn highlight(
+        mut self,
+        points: impl IntoIterator<Item = (usize, usize)>,
+        color: Rgb,
+    ) -> Self {
+        self.cells.extend(points.into_iter().map(|p| (p, color)));
+        self
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        self.cells.get(&(x, y)).copied()
+    }
+}
+
+impl<T> Grid<T> {
+    // A pixel per position, in the overlay's colour where it has one
+    pub fn to_image(&self, color: impl Fn(&T) -> Rgb, overlay: &Overlay) -> Image {
+        let (width, height) = self.dimensions;
+        let mut pixels = Vec::with_capacity(width * height);
+        self.iter_pts(|x, y, elem| {
+            pixels.push(overlay.get(x, y).unwrap_or_else(|| color(elem)));
+        });
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    // For printing to a terminal, with the overlay as 24-bit background colours
+    pub fn to_ansi(&self, format_elem: impl Fn(&T) -> char, overlay: &Overlay) -> String {
+        let mut s = String::new();
+        for y in 0..self.dimensions.1 {
+            let mut current = None;
+            for x in 0..self.dimensions.0 {
+                let highlight = overlay.get(x, y);
+                if highlight != current {
+                    match highlight {
+                        Some([r, g, b]) => write!(s, "\x1b[48;2;{};{};{}m", r, g, b).unwrap(),
+                        None => s.push_str(RESET),
+                    }
+                    current = highlight;
+                }
+                s.push(format_elem(self.at(x, y)));
+            }
+            if current.is_some() {
+                s.push_str(RESET);
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    // Each pixel as a factor x factor square, as a pixel per position is tiny
+    pub fn scaled(&self, factor: usize) -> Image {
+        let (width, height) = (self.width * factor, self.height * factor);
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| self.pixel(x / factor, y / factor)))
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    // Binary PPM (P6), which most image viewers open
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flatten());
+        ppm
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>> {
+        let mut png = vec![];
+        let mut encoder = png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let data: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        encoder.write_header()?.write_image_data(&data)?;
+        Ok(png)
+    }
+
+    // As PNG or PPM, going by the extension
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let contents = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png()?,
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(eyre!(
+                    "Can't save {} (expected .png or .ppm)",
+                    path.display()
+                ))
+            }
+        };
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgb = [255, 0, 0];
+
+    fn sample() -> (Grid<char>, Overlay) {
+        let grid = Grid::parse("#..\n.#.\n", |c| c);
+        let overlay = Overlay::new()
+            .highlight([(1, 0), (2, 0)], [0, 0, 255])
+            .highlight([(2, 0)], RED);
+        (grid, overlay)
+    }
+
+    fn color(c: &char) -> Rgb {
+        if *c == '#' {
+            [0, 0, 0]
+        } else {
+            [255, 255, 255]
+        }
+    }
+
+    #[test]
+    fn renders_images_with_overlays() -> Result<()> {
+        let (grid, overlay) = sample();
+        let image = grid.to_image(color, &overlay);
+        assert_eq!([0, 0, 0], image.pixel(0, 0));
+        assert_eq!([0, 0, 255], image.pixel(1, 0));
+        assert_eq!(RED, image.pixel(2, 0));
+        assert_eq!([255, 255, 255], image.pixel(2, 1));
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(11 + 3 * 2 * 3, ppm.len());
+
+        let png = image.scaled(2).to_png()?;
+        let mut reader = png::Decoder::new(png.as_slice()).read_info()?;
+        let mut data = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut data)?;
+        assert_eq!((6, 4), (info.width, info.height));
+        // The top right pixel
+        assert_eq!(RED, data[15..18]);
+        Ok(())
+    }
+
+    #[test]
+    fn renders_ansi_with_overlays() {
+        let (grid, overlay) = sample();
+        assert_eq!(
+            "#\x1b[48;2;0;0;255m.\x1b[48;2;255;0;0m.\x1b[0m\n.#.\n",
+            grid.to_ansi(|c| *c, &overlay)
+        );
+    }
+
+    #[test]
+    fn saves_by_extension() {
+        let (grid, overlay) = sample();
+        let err = grid.to_image(color, &overlay).save("grid.jpg").unwrap_err();
+        assert_eq!(
+            "Can't save grid.jpg (expected .png or .ppm)",
+            err.to_string()
+        );
+    }
+}
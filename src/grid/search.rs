@@ -0,0 +1,171 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+};
+
+use super::{Dir, Grid};
+
+// How far every position is from the nearest start, and the way back from each
+pub struct Paths {
+    pub distances: Grid<Option<u64>>,
+    previous: Grid<Option<(usize, usize)>>,
+}
+
+impl Paths {
+    fn new<T>(grid: &Grid<T>) -> Self {
+        let (dim_x, dim_y) = grid.dimensions;
+        Self {
+            distances: Grid::new(None, dim_x, dim_y),
+            previous: Grid::new(None, dim_x, dim_y),
+        }
+    }
+
+    pub fn distance(&self, x: usize, y: usize) -> Option<u64> {
+        *self.distances.at(x, y)
+    }
+
+    // From the nearest start to the position, including both
+    pub fn path_to(&self, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
+        self.distance(x, y)?;
+        let mut path = vec![(x, y)];
+        let (mut x, mut y) = (x, y);
+        while let Some(previous) = *self.previous.at(x, y) {
+            path.push(previous);
+            (x, y) = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<T> Grid<T> {
+    // Steps to every position from the nearest start, only going through the
+    // passable positions. The starts themselves are always included.
+    pub fn bfs(
+        &self,
+        starts: impl IntoIterator<Item = (usize, usize)>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Paths {
+        let mut paths = Paths::new(self);
+        let mut queue = VecDeque::new();
+        for (x, y) in starts {
+            if paths.distance(x, y).is_none() {
+                *paths.distances.at_mut(x, y) = Some(0);
+                queue.push_back((x, y, 0));
+            }
+        }
+        while let Some((x, y, dist)) = queue.pop_front() {
+            for (_, (nx, ny)) in self.neighbors4(x, y) {
+                if paths.distance(nx, ny).is_none() && passable(self.at(nx, ny)) {
+                    *paths.distances.at_mut(nx, ny) = Some(dist + 1);
+                    *paths.previous.at_mut(nx, ny) = Some((x, y));
+                    queue.push_back((nx, ny, dist + 1));
+                }
+            }
+        }
+        paths
+    }
+
+    // The positions connected to the start (which is always in it) through matching ones
+    pub fn flood_fill(&self, start: (usize, usize), matches: impl Fn(&T) -> bool) -> Grid<bool> {
+        self.bfs([start], matches).distances.map(Option::is_some)
+    }
+
+    // Cheapest total cost to every position from the nearest start, where cost gives
+    // what stepping onto a position in the given direction costs, or None if it can't
+    // be stepped onto that way
+    pub fn dijkstra(
+        &self,
+        starts: impl IntoIterator<Item = (usize, usize)>,
+        cost: impl Fn(&T, Dir) -> Option<u64>,
+    ) -> Paths {
+        let mut paths = Paths::new(self);
+        let mut heap = BinaryHeap::new();
+        for (x, y) in starts {
+            *paths.distances.at_mut(x, y) = Some(0);
+            heap.push(Reverse((0, x, y)));
+        }
+        while let Some(Reverse((dist, x, y))) = heap.pop() {
+            if paths.distance(x, y).is_some_and(|d| d < dist) {
+                continue;
+            }
+            for (dir, (nx, ny)) in self.neighbors4(x, y) {
+                let Some(step) = cost(self.at(nx, ny), dir) else {
+                    continue;
+                };
+                if paths.distance(nx, ny).is_none_or(|d| dist + step < d) {
+                    *paths.distances.at_mut(nx, ny) = Some(dist + step);
+                    *paths.previous.at_mut(nx, ny) = Some((x, y));
+                    heap.push(Reverse((dist + step, nx, ny)));
+                }
+            }
+        }
+        paths
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+S.#.
+.##.
+...#
+#...
+";
+
+    #[test]
+    fn bfs_finds_distances_and_paths() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let paths = grid.bfs([(0, 0)], |&c| c != '#');
+        assert_eq!(Some(6), paths.distance(3, 3));
+        assert_eq!(None, paths.distance(3, 0));
+        assert_eq!(None, paths.distance(2, 0));
+        let path = paths.path_to(3, 3).unwrap();
+        assert_eq!(7, path.len());
+        assert_eq!(((0, 0), (3, 3)), (path[0], path[6]));
+        assert_eq!(None, paths.path_to(3, 0));
+
+        // From both ends at once
+        let paths = grid.bfs([(0, 0), (3, 3)], |&c| c != '#');
+        assert_eq!(Some(3), paths.distance(1, 2));
+        assert_eq!(Some(2), paths.distance(2, 2));
+    }
+
+    #[test]
+    fn flood_fills() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let filled = grid.flood_fill((3, 0), |&c| c == '.');
+        assert_eq!(vec![(3, 0), (3, 1)], on(&filled));
+        let walls = grid.flood_fill((2, 0), |&c| c == '#');
+        assert_eq!(vec![(2, 0), (1, 1), (2, 1)], on(&walls));
+    }
+
+    fn on(grid: &Grid<bool>) -> Vec<(usize, usize)> {
+        let mut points = vec![];
+        grid.iter_pts(|x, y, &filled| {
+            if filled {
+                points.push((x, y));
+            }
+        });
+        points
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let grid = Grid::parse("1191\n9111\n9999\n", |c| c.to_digit(10).unwrap() as u64);
+        let paths = grid.dijkstra([(0, 0)], |&cost, _| Some(cost));
+        assert_eq!(Some(5), paths.distance(3, 0));
+        assert_eq!(
+            vec![(0, 0), (1, 0), (1, 1), (2, 1), (3, 1), (3, 0)],
+            paths.path_to(3, 0).unwrap()
+        );
+
+        // Only going east or south
+        let paths = grid.dijkstra([(0, 0)], |&cost, dir| {
+            matches!(dir, Dir::East | Dir::South).then_some(cost)
+        });
+        assert_eq!(Some(11), paths.distance(3, 0));
+    }
+}
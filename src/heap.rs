@@ -0,0 +1,220 @@
+use std::fmt::Display;
+#[cfg(any(test, feature = "dhat-heap"))]
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+use crate::report::format_bytes;
+
+// Heap usage measurements are only available when built with the dhat-heap feature.
+// Otherwise measure() just runs the closure.
+pub const ENABLED: bool = cfg!(feature = "dhat-heap");
+
+// What one day (or one phase of it) allocated. The peak is the most bytes it held at
+// once, on top of whatever was already allocated when it started, and is never less
+// than the peak of a measurement inside it (e.g. a day's phases).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct HeapUsage {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak_bytes: u64,
+}
+
+impl Display for HeapUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocs, {}, peak {}",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+#[cfg(feature = "dhat-heap")]
+pub use tracking::Alloc;
+
+#[cfg(feature = "dhat-heap")]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<HeapUsage>) {
+    let before = dhat::HeapStats::get();
+    let mark = tracking::PEAKS.start();
+    let result = f();
+    let peak_bytes = tracking::PEAKS.finish(mark);
+    let after = dhat::HeapStats::get();
+    let usage = HeapUsage {
+        allocations: after.total_blocks - before.total_blocks,
+        bytes: after.total_bytes - before.total_bytes,
+        peak_bytes: peak_bytes as u64,
+    };
+    (result, Some(usage))
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<HeapUsage>) {
+    (f(), None)
+}
+
+// The bytes currently allocated and the peak since the innermost measurement started.
+// Measurements nest: starting one saves the outer peak, and finishing it puts that
+// back, so the outer one still covers everything. As an inner measurement may start
+// after memory held at the outer start is freed, its peak can be higher than the
+// outer one's own, so the highest inner peak is kept as well.
+#[cfg(any(test, feature = "dhat-heap"))]
+struct Peaks {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+    inner_peak: AtomicUsize,
+}
+
+#[cfg(any(test, feature = "dhat-heap"))]
+struct Mark {
+    start: usize,
+    outer_peak: usize,
+    outer_inner_peak: usize,
+}
+
+#[cfg(any(test, feature = "dhat-heap"))]
+impl Peaks {
+    const fn new() -> Self {
+        Self {
+            current: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+            inner_peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn grow(&self, size: usize) {
+        let current = self.current.fetch_add(size, Ordering::SeqCst) + size;
+        self.peak.fetch_max(current, Ordering::SeqCst);
+    }
+
+    fn shrink(&self, size: usize) {
+        self.current.fetch_sub(size, Ordering::SeqCst);
+    }
+
+    fn start(&self) -> Mark {
+        let start = self.current.load(Ordering::SeqCst);
+        Mark {
+            start,
+            outer_peak: self.peak.swap(start, Ordering::SeqCst),
+            outer_inner_peak: self.inner_peak.swap(0, Ordering::SeqCst),
+        }
+    }
+
+    // The measurement's peak, above what was allocated when it started
+    fn finish(&self, mark: Mark) -> usize {
+        let own = self.peak.fetch_max(mark.outer_peak, Ordering::SeqCst) - mark.start;
+        let peak = own.max(self.inner_peak.load(Ordering::SeqCst));
+        self.inner_peak
+            .store(peak.max(mark.outer_inner_peak), Ordering::SeqCst);
+        peak
+    }
+}
+
+// dhat only keeps the peak for the whole run, so this wraps its allocator to also
+// track a peak per measurement
+#[cfg(feature = "dhat-heap")]
+mod tracking {
+    use std::{
+        alloc::{GlobalAlloc, Layout},
+        cell::Cell,
+    };
+
+    use super::Peaks;
+
+    pub(super) static PEAKS: Peaks = Peaks::new();
+
+    thread_local! {
+        static IN_DHAT: Cell<bool> = const { Cell::new(false) };
+    }
+
+    // Runs one call into dhat's allocator. dhat allocates its own bookkeeping from
+    // inside those calls, which is reported as nested and isn't counted.
+    fn dhat_call<T>(f: impl FnOnce() -> T) -> (T, bool) {
+        let nested = IN_DHAT.replace(true);
+        let result = f();
+        IN_DHAT.set(nested);
+        (result, nested)
+    }
+
+    pub struct Alloc;
+
+    fn grow(size: usize) {
+        PEAKS.grow(size);
+    }
+
+    fn shrink(size: usize) {
+        PEAKS.shrink(size);
+    }
+    unsafe impl GlobalAlloc for Alloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let (ptr, nested) = dhat_call(|| dhat::Alloc.alloc(layout));
+            if !nested && !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let (ptr, nested) = dhat_call(|| dhat::Alloc.alloc_zeroed(layout));
+            if !nested && !ptr.is_null() {
+                grow(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let (new_ptr, nested) = dhat_call(|| dhat::Alloc.realloc(ptr, layout, new_size));
+            if !nested && !new_ptr.is_null() {
+                shrink(layout.size());
+                grow(new_size);
+            }
+            new_ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            let ((), nested) = dhat_call(|| dhat::Alloc.dealloc(ptr, layout));
+            if !nested {
+                shrink(layout.size());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peaks_cover_nested_measurements() {
+        let peaks = Peaks::new();
+        // Held before the day starts, and freed before parsing
+        peaks.grow(1000);
+        let day = peaks.start();
+        peaks.shrink(1000);
+        let parse = peaks.start();
+        peaks.grow(500);
+        let parse_peak = peaks.finish(parse);
+        let part1 = peaks.start();
+        peaks.grow(200);
+        peaks.shrink(200);
+        let part1_peak = peaks.finish(part1);
+        let part2 = peaks.start();
+        peaks.grow(100);
+        let part2_peak = peaks.finish(part2);
+        let day_peak = peaks.finish(day);
+
+        assert_eq!((500, 200, 100), (parse_peak, part1_peak, part2_peak));
+        assert!(day_peak >= parse_peak && day_peak >= part1_peak && day_peak >= part2_peak);
+        assert_eq!(500, day_peak);
+
+        // Allocating more after the phases than during any of them
+        let day = peaks.start();
+        let part1 = peaks.start();
+        peaks.grow(10);
+        assert_eq!(10, peaks.finish(part1));
+        peaks.grow(3000);
+        assert_eq!(3010, peaks.finish(day));
+    }
+}
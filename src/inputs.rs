@@ -0,0 +1,216 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::SystemTime,
+};
+
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+
+// Where the inputs/<year>/<day>/ directories live, "inputs" (relative to the working
+// directory) unless overridden with --inputs-dir
+static DIR: OnceLock<PathBuf> = OnceLock::new();
+
+// Before there were years, inputs were kept in inputs/<day>/. Files there are still
+// read as this year's if they're missing from the new layout.
+const LEGACY_YEAR: u32 = 2023;
+
+// With the embed-inputs feature, build.rs bakes the inputs, samples and answers
+// manifests under inputs/ into the binary as (year, day, filename, contents), so it
+// can be run without the directory.
+#[cfg(feature = "embed-inputs")]
+include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+#[cfg(not(feature = "embed-inputs"))]
+static EMBEDDED: &[(u32, usize, &str, &str)] = &[];
+
+pub fn set_dir(dir: impl Into<PathBuf>) -> Result<()> {
+    DIR.set(dir.into())
+        .map_err(|_| eyre!("Inputs directory already set"))
+}
+
+fn dir() -> &'static Path {
+    DIR.get_or_init(|| PathBuf::from("inputs"))
+}
+
+pub fn path(year: u32, day: usize, filename: &str) -> PathBuf {
+    path_in(dir(), year, day, filename)
+}
+
+fn path_in(dir: &Path, year: u32, day: usize, filename: &str) -> PathBuf {
+    dir.join(year.to_string())
+        .join(day.to_string())
+        .join(filename)
+}
+
+// Reads one of the day's files, preferring the inputs directory over embedded copies.
+// A file that's in neither is None.
+pub fn read_optional(year: u32, day: usize, filename: &str) -> Result<Option<String>> {
+    match read_from(dir(), year, day, filename)? {
+        Some(s) => Ok(Some(s)),
+        None => Ok(embedded(year, day, filename).map(str::to_string)),
+    }
+}
+
+// Only the inputs directory, for files that are written back (e.g. the submission
+// history) or whose absence means they still need to be fetched
+pub fn read_from_disk(year: u32, day: usize, filename: &str) -> Result<Option<String>> {
+    read_from(dir(), year, day, filename)
+}
+
+pub fn read(year: u32, day: usize, filename: &str) -> Result<String> {
+    if let Some(s) = read_optional(year, day, filename)? {
+        return Ok(s);
+    }
+    // Neither on disk nor embedded, so this reports the missing file
+    let path = path(year, day, filename);
+    std::fs::read_to_string(&path).wrap_err_with(|| format!("Reading {}", path.display()))
+}
+
+// When the file on disk was last changed, if there is one
+pub fn modified(year: u32, day: usize, filename: &str) -> Option<SystemTime> {
+    disk_paths(dir(), year, day, filename)
+        .iter()
+        .find_map(|path| std::fs::metadata(path).and_then(|m| m.modified()).ok())
+}
+
+// Where the file can be on disk, in the order they're tried
+fn disk_paths(dir: &Path, year: u32, day: usize, filename: &str) -> Vec<PathBuf> {
+    let mut paths = vec![path_in(dir, year, day, filename)];
+    if year == LEGACY_YEAR {
+        paths.push(dir.join(day.to_string()).join(filename));
+    }
+    paths
+}
+
+fn read_from(dir: &Path, year: u32, day: usize, filename: &str) -> Result<Option<String>> {
+    for path in disk_paths(dir, year, day, filename) {
+        match std::fs::read_to_string(&path) {
+            Ok(s) => return Ok(Some(s)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).wrap_err_with(|| format!("Reading {}", path.display())),
+        }
+    }
+    Ok(None)
+}
+
+fn embedded(year: u32, day: usize, filename: &str) -> Option<&'static str> {
+    EMBEDDED
+        .iter()
+        .find(|(y, d, f, _)| *y == year && *d == day && *f == filename)
+        .map(|(_, _, _, contents)| *contents)
+}
+
+// The names of the day's files, on disk (in either layout) or embedded
+pub fn list(year: u32, day: usize) -> Result<Vec<String>> {
+    let mut names = list_in(dir(), year, day)?;
+    names.extend(
+        EMBEDDED
+            .iter()
+            .filter(|(y, d, _, _)| *y == year && *d == day)
+            .map(|(_, _, f, _)| f.to_string()),
+    );
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+// input.txt and the samples, but not the manifests
+pub fn puzzle_files(year: u32, day: usize) -> Result<Vec<String>> {
+    Ok(list(year, day)?
+        .into_iter()
+        .filter(|f| f == "input.txt" || (f.starts_with("sample") && f.ends_with(".txt")))
+        .collect())
+}
+
+// Samples that only work for one part are named sample_part1*.txt or sample_part2*.txt
+pub fn only_part(filename: &str) -> Option<usize> {
+    match filename.strip_prefix("sample_part")?.chars().next()? {
+        '1' => Some(1),
+        '2' => Some(2),
+        _ => None,
+    }
+}
+
+fn list_in(dir: &Path, year: u32, day: usize) -> Result<Vec<String>> {
+    let mut dirs = vec![path_in(dir, year, day, "")];
+    if year == LEGACY_YEAR {
+        dirs.push(dir.join(day.to_string()));
+    }
+    let mut names = vec![];
+    for dir in dirs {
+        let entries = match std::fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(e).wrap_err_with(|| format!("Reading {}", dir.display())),
+        };
+        for entry in entries {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                names.extend(entry.file_name().to_str().map(str::to_string));
+            }
+        }
+    }
+    Ok(names)
+}
+
+// Writes one of the day's files into the inputs directory (always in the
+// inputs/<year>/<day>/ layout)
+pub fn write(year: u32, day: usize, filename: &str, contents: &str) -> Result<()> {
+    let path = path(year, day, filename);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, contents).wrap_err_with(|| format!("Writing {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_files_are_none() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("2023").join("3"))?;
+        std::fs::write(dir.join("2023").join("3").join("input.txt"), "467..114..")?;
+        assert_eq!(
+            Some("467..114..".to_string()),
+            read_from(&dir, 2023, 3, "input.txt")?
+        );
+        assert_eq!(None, read_from(&dir, 2023, 3, "answers.toml")?);
+        assert_eq!(None, read_from(&dir, 2023, 4, "input.txt")?);
+        assert_eq!(None, read_from(&dir, 2022, 3, "input.txt")?);
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn finds_single_part_samples() {
+        assert_eq!(None, only_part("input.txt"));
+        assert_eq!(None, only_part("sample_2.txt"));
+        assert_eq!(Some(1), only_part("sample_part1.txt"));
+        assert_eq!(Some(2), only_part("sample_part2_3.txt"));
+    }
+
+    #[test]
+    fn falls_back_to_legacy_layout() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-legacy-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("3"))?;
+        std::fs::write(dir.join("3").join("input.txt"), "467..114..")?;
+        assert_eq!(
+            Some("467..114..".to_string()),
+            read_from(&dir, 2023, 3, "input.txt")?
+        );
+        assert_eq!(None, read_from(&dir, 2024, 3, "input.txt")?);
+
+        std::fs::create_dir_all(dir.join("2023").join("3"))?;
+        std::fs::write(dir.join("2023").join("3").join("sample.txt"), "...*......")?;
+        let mut names = list_in(&dir, 2023, 3)?;
+        names.sort();
+        assert_eq!(vec!["input.txt", "sample.txt"], names);
+        assert!(list_in(&dir, 2024, 3)?.is_empty());
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
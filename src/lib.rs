@@ -0,0 +1,61 @@
+// The solutions and everything to run them, so they can be used from other tools and
+// benches as well as the aoc-2023 binary
+use color_eyre::{eyre::eyre, Result};
+
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod fetch;
+pub mod heap;
+pub mod inputs;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod selection;
+pub mod solution;
+pub mod submit;
+pub mod symbol_table;
+pub mod watch;
+
+pub mod grid;
+
+pub mod year2023;
+
+pub use answers::Answer;
+pub use solution::{Day, Solution, Year};
+
+// Days fetch can select from, for years without solutions yet
+pub const MAX_DAYS: usize = 25;
+
+// Every year with solutions, in order. The last one is the default for --year.
+pub const YEARS: &[Year] = &[year2023::YEAR];
+
+// The given year's solutions, or the newest year's
+pub fn find_year(year: Option<u32>) -> Result<&'static Year> {
+    match year {
+        None => Ok(&YEARS[YEARS.len() - 1]),
+        Some(year) => YEARS
+            .iter()
+            .find(|y| y.year == year)
+            .ok_or_else(|| eyre!("No solutions for {}", year)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_years_and_days() -> Result<()> {
+        let year = find_year(None)?;
+        assert_eq!(2023, year.year);
+        assert!(find_year(Some(2022)).is_err());
+
+        let day = year.day(6).expect("day 6 is solved");
+        let (p1, p2) = (day.run)("Time:      7  15   30\nDistance:  9  40  200\n")?;
+        assert_eq!((Answer::Int(288), Answer::Int(71503)), (p1, p2));
+        assert!(year.day(0).is_none());
+        assert!(year.day(year.days.len() + 1).is_none());
+        Ok(())
+    }
+}
@@ -1,76 +1,45 @@
 use std::time::{Duration, Instant};
 
-mod runner;
-mod symbol_table;
-
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day2;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod grid;
+use color_eyre::eyre::eyre;
 
+use aoc_2023::baseline::Baseline;
+use aoc_2023::bench::{self, bench_day, BenchOptions, DayStats};
+use aoc_2023::report::{format_runtime_duration, DayReport, Format, Report};
+use aoc_2023::runner::{check_day, normal_day, read_input, record_day};
+use aoc_2023::selection::DaySet;
+use aoc_2023::{fetch, find_year, heap, inputs, scaffold, submit, watch, Year, MAX_DAYS, YEARS};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
-use runner::normal_day;
 
 #[derive(Debug)]
 struct AppArgs {
+    opt_command: Option<String>,
+    opt_year: Option<u32>,
     opt_profile_day: Option<usize>,
     profile_times: usize,
     parallel: bool,
+    bench: bool,
+    warmup: usize,
+    opt_save_baseline: Option<String>,
+    opt_compare: Option<String>,
+    max_regression: f64,
+    format: Format,
+    opt_day: Option<usize>,
+    opt_days: Option<DaySet>,
+    opt_exclude: Option<DaySet>,
+    opt_input: Option<String>,
+    opt_inputs_dir: Option<String>,
+    opt_part: Option<usize>,
+    phases: bool,
+    record: bool,
+    sample: bool,
+    force: bool,
+    opt_base_url: Option<String>,
+    opt_free_day: Option<usize>,
 }
 
-const DAYS: [fn() -> color_eyre::Result<()>; 25] = [
-    || normal_day(day1::run, 1, 55816, 54980),
-    || normal_day(day2::run, 2, 2685, 83707),
-    || normal_day(day3::run, 3, 527369, 73074886),
-    || normal_day(day4::run, 4, 17782, 8477787),
-    || normal_day(day5::run, 5, 251346198, 72263011),
-    || normal_day(day6::run, 6, 512295, 36530883),
-    || normal_day(day7::run, 7, 250347426, 251224870),
-    || normal_day(day8::run, 8, 15517, 14935034899483),
-    || normal_day(day9::run, 9, 1819125966, 1140),
-    || normal_day(day10::run, 10, 6923, 529),
-    || normal_day(day11::run, 11, 9329143, 710674907809),
-    || normal_day(day12::run, 12, 6852, 8475948826693),
-    || normal_day(day13::run, 13, 37718, 40995),
-    || normal_day(day14::run, 14, 102497, 105008),
-    || normal_day(day15::run, 15, 516657, 210906),
-    || normal_day(day16::run, 16, 6514, 8089),
-    || normal_day(day17::run, 17, 1155, 1283),
-    || normal_day(day18::run, 18, 31171, 131431655002266),
-    || normal_day(day19::run, 19, 374873, 122112157518711),
-    || normal_day(day20::run, 20, 743090292, 241528184647003),
-    || normal_day(day21::run, 21, 3591, 598044246091826),
-    || normal_day(day22::run, 22, 499, 95059),
-    || normal_day(day23::run, 23, 2186, 6802),
-    || normal_day(day24::run, 24, 19523, 566373506408017),
-    || normal_day(day25::run, 25, 506202, 0),
-];
-
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
-static ALLOC: dhat::Alloc = dhat::Alloc;
+static ALLOC: heap::Alloc = heap::Alloc;
 
 fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
@@ -86,50 +55,269 @@ fn main() -> color_eyre::Result<()> {
         }
     };
 
-    if let Some(d) = args.opt_profile_day {
-        profile_one_day(d, args.profile_times)?;
+    if let Some(dir) = &args.opt_inputs_dir {
+        inputs::set_dir(dir)?;
+    }
+
+    // Fetching and scaffolding also work for years without any solutions yet
+    let year_number = args.opt_year.unwrap_or(YEARS[YEARS.len() - 1].year);
+    if let Some(command) = &args.opt_command {
+        match command.as_str() {
+            "fetch" => {
+                let client = fetch::Client::from_env(args.opt_base_url.as_deref())?;
+                for day in selected_days(&args, MAX_DAYS)? {
+                    fetch::fetch_day(&client, year_number, day, args.sample, args.force)?;
+                }
+            }
+            "submit" => submit_answer(find_year(args.opt_year)?, &args)?,
+            "new-day" => {
+                let day = args
+                    .opt_day
+                    .or(args.opt_free_day)
+                    .ok_or_else(|| eyre!("new-day requires a day number"))?;
+                let registered = find_year(args.opt_year).map_or(0, |y| y.days.len());
+                scaffold::new_day(year_number, day, registered)?;
+            }
+            "watch" => {
+                let day = args
+                    .opt_day
+                    .or(args.opt_free_day)
+                    .ok_or_else(|| eyre!("watch requires a day number"))?;
+                watch::watch(find_year(args.opt_year)?, day)?;
+            }
+            _ => {
+                return Err(eyre!(
+                    "Unknown command {} (expected fetch, submit, new-day or watch)",
+                    command
+                ))
+            }
+        }
+        return Ok(());
+    }
+
+    let year = find_year(args.opt_year)?;
+    let days = || selected_days(&args, year.days.len());
+    if args.record {
+        if args.opt_input.is_some() {
+            return Err(eyre!(
+                "--record only records answers for inputs/<year>/<day>/input.txt"
+            ));
+        }
+        record_days(year, &days()?)?;
+    } else if args.bench || args.opt_save_baseline.is_some() || args.opt_compare.is_some() {
+        let options = BenchOptions {
+            warmup: args.warmup,
+            iterations: args.profile_times,
+        };
+        let results = bench_days(year, &days()?, options, args.format)?;
+        // Compared before saving, so a run isn't compared against itself when both name
+        // the same file
+        if let Some(path) = &args.opt_compare {
+            let comparison = Baseline::load(path)?.compare(&results, args.max_regression);
+            comparison.print_text();
+            if comparison.failures() > 0 {
+                return Err(eyre!(
+                    "{} regression(s) or budget overrun(s) against {}",
+                    comparison.failures(),
+                    path
+                ));
+            }
+        }
+        if let Some(path) = &args.opt_save_baseline {
+            Baseline::save(path, &results)?;
+        }
+    } else if let Some(d) = args.opt_day {
+        solve_one_input(year, d, args.opt_input.as_deref(), args.opt_part)?;
+    } else if args.opt_input.is_some() || args.opt_part.is_some() {
+        return Err(eyre!("--input and --part require --day"));
+    } else if let Some(d) = args.opt_profile_day {
+        profile_one_day(year, d, args.profile_times)?;
     } else {
-        run_all_days(args.parallel)?;
+        run_all_days(year, &days()?, args.parallel, args.format, args.phases)?;
     }
 
     Ok(())
 }
 
-fn run_all_days(parallel: bool) -> color_eyre::Result<()> {
+fn run_all_days(
+    year: &Year,
+    days: &[usize],
+    parallel: bool,
+    format: Format,
+    phases: bool,
+) -> color_eyre::Result<()> {
+    if parallel && heap::ENABLED {
+        return Err(eyre!(
+            "Heap usage can only be measured per day in serial runs"
+        ));
+    }
     let start = Instant::now();
-    if parallel {
-        DAYS.par_iter().for_each(|d| {
-            d().unwrap();
-        });
+    let report = if parallel {
+        // Days finish in any order, so they're only printed once all are done
+        let days: Vec<DayReport> = days
+            .par_iter()
+            .map(|&day| {
+                let day_start = Instant::now();
+                let result = check_day(&year.days[day - 1], year.year, day, phases);
+                let mut day = DayReport::new(day, day_start.elapsed(), result);
+                day.thread = rayon::current_thread_index();
+                day
+            })
+            .collect();
+        let report = Report::new(days, start.elapsed());
+        if format == Format::Text {
+            report.days.iter().for_each(DayReport::print_text);
+        }
+        report
     } else {
-        // Run in serial
-        for (i, d) in DAYS.iter().enumerate() {
+        let mut reports = Vec::with_capacity(days.len());
+        for &day in days {
             let day_start = Instant::now();
-            d()?;
-            println!("Day {}:\t{}", i + 1, format_runtime_elapsed(&day_start));
+            let result = check_day(&year.days[day - 1], year.year, day, phases);
+            let day = DayReport::new(day, day_start.elapsed(), result);
+            if format == Format::Text {
+                day.print_text();
+            }
+            reports.push(day);
         }
+        Report::new(reports, start.elapsed())
+    };
+
+    match format {
+        Format::Text => report.print_total_text(),
+        Format::Json => println!("{}", report.to_json()?),
+        Format::Csv => print!("{}", report.to_csv()),
+    }
+    if report.failures() > 0 {
+        return Err(eyre!("{} day(s) failed", report.failures()));
     }
+    Ok(())
+}
 
-    let duration = Instant::now().duration_since(start);
+// Solves inputs/<year>/<day>/input.txt for the given days and writes the answers to the
+// manifests
+fn record_days(year: &Year, days: &[usize]) -> color_eyre::Result<()> {
+    for &day in days {
+        let (p1, p2) = record_day(&year.days[day - 1], year.year, day)?;
+        println!("Day {}: recorded part 1 = {}, part 2 = {}", day, p1, p2);
+    }
+    Ok(())
+}
 
-    let budget = if duration > Duration::from_secs(1) {
-        Duration::ZERO
-    } else {
-        Duration::from_secs(1) - duration
+fn bench_days(
+    year: &Year,
+    days: &[usize],
+    options: BenchOptions,
+    format: Format,
+) -> color_eyre::Result<Vec<DayStats>> {
+    let mut results = vec![];
+    for &day in days {
+        let stats = bench_day(&year.days[day - 1], year.year, day, options)?;
+        if format == Format::Text {
+            stats.print_text(day);
+        }
+        results.push(DayStats { day, stats });
+    }
+    match format {
+        Format::Text => {
+            let total = results.iter().map(|d| d.stats.median_us).sum::<f64>() / 1e6;
+            println!(
+                "Total of medians: {}.",
+                format_runtime_duration(&Duration::from_secs_f64(total))
+            );
+        }
+        Format::Json => println!("{}", serde_json::to_string_pretty(&results)?),
+        Format::Csv => print!("{}", bench::to_csv(&results)),
+    }
+    Ok(results)
+}
+
+fn check_day_exists(day: usize, count: usize) -> color_eyre::Result<()> {
+    if day == 0 || day > count {
+        return Err(eyre!("No solution for day {}", day));
+    }
+    Ok(())
+}
+
+// Either just --day, or the --days selection (all of the count days by default)
+// minus --exclude
+fn selected_days(args: &AppArgs, count: usize) -> color_eyre::Result<Vec<usize>> {
+    let days: Vec<usize> = match (args.opt_day, &args.opt_days) {
+        (Some(_), Some(_)) => return Err(eyre!("--day and --days can't be combined")),
+        (Some(d), None) => vec![d],
+        (None, Some(days)) => days.iter().collect(),
+        (None, None) => (1..=count).collect(),
     };
-    println!(
-        "Total time: {}. Remaining time budget: {}.",
-        format_runtime_duration(&duration),
-        format_runtime_duration(&budget),
-    );
+    for &day in &days {
+        check_day_exists(day, count)?;
+    }
+    let days: Vec<usize> = days
+        .into_iter()
+        .filter(|&d| !args.opt_exclude.as_ref().is_some_and(|e| e.contains(d)))
+        .collect();
+    if days.is_empty() {
+        return Err(eyre!("No days selected"));
+    }
+    Ok(days)
+}
+
+// Solves any input file (or stdin if path is "-", or the day's input.txt if not
+// given) without checking the answers, optionally only solving one part
+fn solve_one_input(
+    year: &Year,
+    day: usize,
+    path: Option<&str>,
+    part: Option<usize>,
+) -> color_eyre::Result<()> {
+    check_day_exists(day, year.days.len())?;
+    let d = &year.days[day - 1];
+    let input = match path {
+        Some(path) => read_input(path)?,
+        None => inputs::read(year.year, day, "input.txt")?,
+    };
+    match part {
+        Some(part) => {
+            let solve = d
+                .part(part)
+                .ok_or_else(|| eyre!("No part {} (expected 1 or 2)", part))?;
+            println!("Day {} part {}: {}", day, part, solve(&input)?);
+        }
+        None => {
+            let (p1, p2) = (d.run)(&input)?;
+            println!("Day {} part 1: {}", day, p1);
+            println!("Day {} part 2: {}", day, p2);
+        }
+    }
+    Ok(())
+}
+
+// Solves inputs/<year>/<day>/input.txt for one part and submits the answer
+fn submit_answer(year: &Year, args: &AppArgs) -> color_eyre::Result<()> {
+    let (Some(day), Some(part)) = (args.opt_day, args.opt_part) else {
+        return Err(eyre!("submit requires --day and --part"));
+    };
+    if args.opt_input.is_some() {
+        return Err(eyre!(
+            "submit only submits answers for inputs/<year>/<day>/input.txt"
+        ));
+    }
+    check_day_exists(day, year.days.len())?;
+    let solve = year.days[day - 1]
+        .part(part)
+        .ok_or_else(|| eyre!("No part {} (expected 1 or 2)", part))?;
+    let answer = solve(&inputs::read(year.year, day, "input.txt")?)?;
+    let client = fetch::Client::from_env(args.opt_base_url.as_deref())?;
+    let verdict = submit::submit_day(&client, year.year, day, part, &answer)?;
+    println!("Day {} part {}: {} is {}", day, part, answer, verdict);
     Ok(())
 }
 
-fn profile_one_day(day: usize, times: usize) -> color_eyre::Result<()> {
+fn profile_one_day(year: &Year, day: usize, times: usize) -> color_eyre::Result<()> {
+    check_day_exists(day, year.days.len())?;
     println!("Profiling running day {} x{}:", day, times);
     let day_start = Instant::now();
     for _ in 0..times {
-        DAYS[day - 1]()?;
+        normal_day(&year.days[day - 1], year.year, day)?;
     }
     let duration = Instant::now().duration_since(day_start);
     println!(
@@ -146,24 +334,35 @@ fn parse_args() -> color_eyre::Result<AppArgs, pico_args::Error> {
     let mut pargs = pico_args::Arguments::from_env();
 
     let args = AppArgs {
+        opt_command: pargs.subcommand()?,
+        opt_year: pargs.opt_value_from_str("--year")?,
         opt_profile_day: pargs.opt_value_from_str("--profile-day")?,
         profile_times: pargs.opt_value_from_str("--profile-times")?.unwrap_or(10),
         parallel: pargs.contains("--parallel"),
+        bench: pargs.contains("--bench"),
+        warmup: pargs.opt_value_from_str("--warmup")?.unwrap_or(3),
+        opt_save_baseline: pargs.opt_value_from_str("--save-baseline")?,
+        opt_compare: pargs.opt_value_from_str("--compare")?,
+        max_regression: pargs
+            .opt_value_from_str("--max-regression")?
+            .unwrap_or(10.0),
+        format: pargs
+            .opt_value_from_fn("--format", str::parse)?
+            .unwrap_or(Format::Text),
+        opt_day: pargs.opt_value_from_str("--day")?,
+        opt_days: pargs.opt_value_from_str("--days")?,
+        opt_exclude: pargs.opt_value_from_str("--exclude")?,
+        opt_input: pargs.opt_value_from_str("--input")?,
+        opt_inputs_dir: pargs.opt_value_from_str("--inputs-dir")?,
+        opt_part: pargs.opt_value_from_str("--part")?,
+        phases: pargs.contains("--phases"),
+        record: pargs.contains("--record"),
+        sample: pargs.contains("--sample"),
+        force: pargs.contains("--force"),
+        opt_base_url: pargs.opt_value_from_str("--base-url")?,
+        // Free arguments have to be parsed last
+        opt_free_day: pargs.opt_free_from_str()?,
     };
 
     Ok(args)
 }
-
-fn format_runtime_elapsed(instant: &Instant) -> String {
-    format_runtime_duration(&instant.elapsed())
-}
-
-fn format_runtime_duration(d: &Duration) -> String {
-    if d.as_micros() < 5000 {
-        format!("{}us", d.as_micros())
-    } else if d.as_millis() < 2000 {
-        format!("{}ms", d.as_millis())
-    } else {
-        format!("{}s", d.as_secs())
-    }
-}
@@ -0,0 +1,464 @@
+use std::{
+    fmt::{Display, Write},
+    str::FromStr,
+    time::Duration,
+};
+
+use color_eyre::{eyre::eyre, Result};
+use serde::Serialize;
+
+use crate::answers::Answer;
+use crate::heap::HeapUsage;
+use crate::runner::Checked;
+
+// We're aiming for all days to run in under this in total
+pub const TIME_BUDGET: Duration = Duration::from_secs(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(eyre!("Unknown format {} (expected text, json or csv)", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Unchecked,
+    Error,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unchecked => "unchecked",
+            Status::Error => "error",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub day: usize,
+    pub time_us: u64,
+    // Only measured when the phases are run separately
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_us: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1_us: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2_us: Option<u64>,
+    // Only measured with the dhat-heap feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heap: Option<HeapUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_heap: Option<HeapUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1_heap: Option<HeapUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2_heap: Option<HeapUsage>,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+    pub expected_part1: Option<Answer>,
+    pub expected_part2: Option<Answer>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    // The rayon worker the day ran on, in parallel runs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<usize>,
+}
+
+impl DayReport {
+    pub fn new(day: usize, elapsed: Duration, result: Result<Checked>) -> Self {
+        let time_us = elapsed.as_micros() as u64;
+        match result {
+            Ok(checked) => Self {
+                status: checked.status(),
+                day,
+                time_us,
+                parse_us: checked.phases.map(|p| p.parse.as_micros() as u64),
+                part1_us: checked.phases.map(|p| p.part1.as_micros() as u64),
+                part2_us: checked.phases.map(|p| p.part2.as_micros() as u64),
+                heap: checked.heap,
+                parse_heap: checked.phases.and_then(|p| p.parse_heap),
+                part1_heap: checked.phases.and_then(|p| p.part1_heap),
+                part2_heap: checked.phases.and_then(|p| p.part2_heap),
+                part1: Some(checked.answers.0),
+                part2: Some(checked.answers.1),
+                expected_part1: checked.expected.part1,
+                expected_part2: checked.expected.part2,
+                error: None,
+                thread: None,
+            },
+            Err(e) => Self {
+                day,
+                time_us,
+                parse_us: None,
+                part1_us: None,
+                part2_us: None,
+                heap: None,
+                parse_heap: None,
+                part1_heap: None,
+                part2_heap: None,
+                part1: None,
+                part2: None,
+                expected_part1: None,
+                expected_part2: None,
+                status: Status::Error,
+                error: Some(format!("{:#}", e)),
+                thread: None,
+            },
+        }
+    }
+
+    pub fn print_text(&self) {
+        let mut time = format_runtime_duration(&Duration::from_micros(self.time_us));
+        if let (Some(parse), Some(part1), Some(part2)) =
+            (self.parse_us, self.part1_us, self.part2_us)
+        {
+            let fmt = |us| format_runtime_duration(&Duration::from_micros(us));
+            time = format!(
+                "{} (parse {}, part 1 {}, part 2 {})",
+                time,
+                fmt(parse),
+                fmt(part1),
+                fmt(part2)
+            );
+        }
+        if let Some(heap) = &self.heap {
+            time = format!("{} heap: {}", time, heap);
+            if let (Some(parse), Some(part1), Some(part2)) =
+                (&self.parse_heap, &self.part1_heap, &self.part2_heap)
+            {
+                time = format!(
+                    "{} (parse: {}; part 1: {}; part 2: {})",
+                    time, parse, part1, part2
+                );
+            }
+        }
+        if let Some(thread) = self.thread {
+            time = format!("{} [thread {}]", time, thread);
+        }
+        match self.status {
+            Status::Pass => println!("Day {}:\t{}", self.day, time),
+            Status::Unchecked => println!("Day {}:\t{} (unchecked)", self.day, time),
+            Status::Fail => println!(
+                "Day {}:\t{} FAILED: expected ({}, {}), got ({}, {})",
+                self.day,
+                time,
+                format_opt(&self.expected_part1),
+                format_opt(&self.expected_part2),
+                format_opt(&self.part1),
+                format_opt(&self.part2),
+            ),
+            Status::Error => println!(
+                "Day {}:\t{} ERROR: {}",
+                self.day,
+                time,
+                self.error.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub days: Vec<DayReport>,
+    pub total_us: u64,
+    pub budget_us: u64,
+    pub status: Status,
+}
+
+impl Report {
+    pub fn new(days: Vec<DayReport>, total: Duration) -> Self {
+        let budget = TIME_BUDGET.saturating_sub(total);
+        let status = if days.iter().any(|d| d.status == Status::Error) {
+            Status::Error
+        } else if days.iter().any(|d| d.status == Status::Fail) {
+            Status::Fail
+        } else if days.iter().any(|d| d.status == Status::Unchecked) {
+            Status::Unchecked
+        } else {
+            Status::Pass
+        };
+        Self {
+            days,
+            total_us: total.as_micros() as u64,
+            budget_us: budget.as_micros() as u64,
+            status,
+        }
+    }
+
+    pub fn failures(&self) -> usize {
+        self.days
+            .iter()
+            .filter(|d| matches!(d.status, Status::Fail | Status::Error))
+            .count()
+    }
+
+    pub fn print_total_text(&self) {
+        println!(
+            "Total time: {}. Remaining time budget: {}.",
+            format_runtime_duration(&Duration::from_micros(self.total_us)),
+            format_runtime_duration(&Duration::from_micros(self.budget_us)),
+        );
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    // One row per day, then a "total" row
+    pub fn to_csv(&self) -> String {
+        let mut s = String::from(
+            "day,time_us,parse_us,part1_us,part2_us,allocations,heap_bytes,peak_heap_bytes,part1,part2,\
+             expected_part1,expected_part2,status,thread\n",
+        );
+        for d in &self.days {
+            writeln!(
+                s,
+                "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                d.day,
+                d.time_us,
+                format_opt(&d.parse_us),
+                format_opt(&d.part1_us),
+                format_opt(&d.part2_us),
+                format_opt(&d.heap.map(|h| h.allocations)),
+                format_opt(&d.heap.map(|h| h.bytes)),
+                format_opt(&d.heap.map(|h| h.peak_bytes)),
+                csv_field(&d.part1),
+                csv_field(&d.part2),
+                csv_field(&d.expected_part1),
+                csv_field(&d.expected_part2),
+                d.status.as_str(),
+                format_opt(&d.thread)
+            )
+            .unwrap();
+        }
+        writeln!(
+            s,
+            "total,{},,,,,,,,,,,{},",
+            self.total_us,
+            self.status.as_str()
+        )
+        .unwrap();
+        s
+    }
+}
+
+fn format_opt<T: Display>(n: &Option<T>) -> String {
+    n.as_ref().map(|n| n.to_string()).unwrap_or_default()
+}
+
+// Quoted as in RFC 4180 where needed, as text answers can hold commas, quotes or
+// several lines
+fn csv_field<T: Display>(value: &Option<T>) -> String {
+    let s = format_opt(value);
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes < 5000 {
+        format!("{}B", bytes)
+    } else if bytes < 5000 * 1024 {
+        format!("{}KB", bytes / 1024)
+    } else {
+        format!("{}MB", bytes / (1024 * 1024))
+    }
+}
+
+pub fn format_runtime_duration(d: &Duration) -> String {
+    if d.as_micros() < 5000 {
+        format!("{}us", d.as_micros())
+    } else if d.as_millis() < 2000 {
+        format!("{}ms", d.as_millis())
+    } else {
+        format!("{}s", d.as_secs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers::Answers;
+    use crate::solution::Phases;
+
+    fn checked(answers: (i64, i64), part1: Option<i64>, part2: Option<i64>) -> Result<Checked> {
+        Ok(Checked {
+            answers: (answers.0.into(), answers.1.into()),
+            expected: Answers {
+                part1: part1.map(Answer::from),
+                part2: part2.map(Answer::from),
+            },
+            phases: None,
+            heap: None,
+        })
+    }
+
+    #[test]
+    fn overall_status_is_worst_day() {
+        let report = Report::new(
+            vec![
+                DayReport::new(
+                    1,
+                    Duration::from_micros(10),
+                    checked((1, 2), Some(1), Some(2)),
+                ),
+                DayReport::new(2, Duration::from_micros(20), checked((1, 2), Some(1), None)),
+            ],
+            Duration::from_micros(30),
+        );
+        assert_eq!(Status::Unchecked, report.status);
+        assert_eq!(0, report.failures());
+
+        let report = Report::new(
+            vec![
+                DayReport::new(
+                    1,
+                    Duration::from_micros(10),
+                    checked((1, 2), Some(1), Some(3)),
+                ),
+                DayReport::new(2, Duration::from_micros(20), Err(eyre!("no input"))),
+            ],
+            Duration::from_micros(30),
+        );
+        assert_eq!(Status::Error, report.status);
+        assert_eq!(2, report.failures());
+    }
+
+    #[test]
+    fn csv_has_a_row_per_day_and_total() {
+        let report = Report::new(
+            vec![DayReport::new(
+                3,
+                Duration::from_micros(321),
+                checked((527369, 73074886), Some(527369), None),
+            )],
+            Duration::from_micros(400),
+        );
+        assert_eq!(
+            "day,time_us,parse_us,part1_us,part2_us,allocations,heap_bytes,peak_heap_bytes,part1,part2,\
+             expected_part1,expected_part2,status,thread\n\
+             3,321,,,,,,,527369,73074886,527369,,unchecked,\n\
+             total,400,,,,,,,,,,,unchecked,\n",
+            report.to_csv()
+        );
+    }
+
+    #[test]
+    fn csv_quotes_text_answers() {
+        let day = DayReport::new(
+            10,
+            Duration::from_micros(10),
+            Ok(Checked {
+                answers: (Answer::Text("a,b".into()), Answer::Text("#.\n\"x\"".into())),
+                expected: Answers::default(),
+                phases: None,
+                heap: None,
+            }),
+        );
+        let report = Report::new(vec![day], Duration::from_micros(10));
+        assert!(report
+            .to_csv()
+            .contains("\n10,10,,,,,,,\"a,b\",\"#.\n\"\"x\"\"\",,,unchecked,\n"));
+    }
+
+    #[test]
+    fn parts_without_an_answer_need_no_expectation() {
+        let day = DayReport::new(
+            25,
+            Duration::from_micros(10),
+            Ok(Checked {
+                answers: (Answer::Int(-54), Answer::None),
+                expected: Answers {
+                    part1: Some(Answer::Int(-54)),
+                    part2: None,
+                },
+                phases: None,
+                heap: None,
+            }),
+        );
+        assert_eq!(Status::Pass, day.status);
+        let report = Report::new(vec![day], Duration::from_micros(10));
+        assert!(report.to_csv().contains("\n25,10,,,,,,,-54,-,-54,,pass,\n"));
+        assert!(report.to_json().unwrap().contains("\"part2\": null"));
+    }
+
+    #[test]
+    fn includes_phase_timings() {
+        let day = DayReport::new(
+            3,
+            Duration::from_micros(321),
+            Ok(Checked {
+                answers: (Answer::Int(1), Answer::Int(2)),
+                expected: Answers::default(),
+                phases: Some(Phases {
+                    parse: Duration::from_micros(100),
+                    part1: Duration::from_micros(20),
+                    part2: Duration::from_micros(200),
+                    ..Default::default()
+                }),
+                heap: None,
+            }),
+        );
+        let report = Report::new(vec![day], Duration::from_micros(400));
+        assert!(report
+            .to_csv()
+            .contains("\n3,321,100,20,200,,,,1,2,,,unchecked,\n"));
+        assert!(report.to_json().unwrap().contains("\"part1_us\": 20"));
+    }
+
+    #[test]
+    fn includes_heap_usage() {
+        let usage = |allocations, bytes, peak_bytes| HeapUsage {
+            allocations,
+            bytes,
+            peak_bytes,
+        };
+        let day = DayReport::new(
+            14,
+            Duration::from_micros(321),
+            Ok(Checked {
+                answers: (Answer::Int(1), Answer::Int(2)),
+                expected: Answers::default(),
+                phases: Some(Phases {
+                    part2_heap: Some(usage(90, 9000, 3000)),
+                    ..Default::default()
+                }),
+                heap: Some(usage(100, 10000, 4000)),
+            }),
+        );
+        assert_eq!(Some(usage(90, 9000, 3000)), day.part2_heap);
+        let report = Report::new(vec![day], Duration::from_micros(400));
+        assert!(report
+            .to_csv()
+            .contains("\n14,321,0,0,0,100,10000,4000,1,2,,,unchecked,\n"));
+        assert!(report.to_json().unwrap().contains("\"peak_bytes\": 3000"));
+        assert_eq!(
+            "100 allocs, 9KB, peak 4000B",
+            usage(100, 10000, 4000).to_string()
+        );
+    }
+}
@@ -1,58 +1,160 @@
-pub fn test_one_file<T>(
-    f: fn(&str) -> color_eyre::Result<(T, T)>,
-    day: usize,
-    filename: &str,
-    part1: Option<T>,
-    part2: Option<T>,
-) -> color_eyre::Result<()>
-where
-    T: PartialEq,
-    T: std::fmt::Debug,
-{
-    let input = std::fs::read_to_string(format!("inputs/{}/{}", day, filename))?;
-    let (p1, p2) = f(&input)?;
-    if let Some(p1_ans) = part1 {
-        assert_eq!(p1_ans, p1);
-    }
-    if let Some(p2_ans) = part2 {
-        assert_eq!(p2_ans, p2);
+use std::{any::Any, io::Read, panic};
+
+use color_eyre::eyre::eyre;
+
+use crate::answers::{Answer, Answers, Manifest};
+use crate::heap::{self, HeapUsage};
+use crate::inputs;
+use crate::report::Status;
+use crate::solution::{Day, Phases};
+
+// Checks one of the day's files against its answers manifest, only solving the one part
+// for sample_part1*.txt and sample_part2*.txt files. Parts with no known answer are
+// only reported.
+#[cfg(test)]
+pub fn test_file(year: u32, day: usize, filename: &str) -> color_eyre::Result<()> {
+    let d = crate::find_year(Some(year))?
+        .day(day)
+        .ok_or_else(|| eyre!("No solution for day {}", day))?;
+    let input = inputs::read(year, day, filename)?;
+    let expected = Manifest::load(year, day)?.get(filename);
+    let (p1, p2) = match inputs::only_part(filename) {
+        Some(1) => (Some((d.part1)(&input)?), None),
+        Some(_) => (None, Some((d.part2)(&input)?)),
+        None => {
+            let (p1, p2) = (d.run)(&input)?;
+            (Some(p1), Some(p2))
+        }
+    };
+    for (part, answer, expected) in [(1, p1, expected.part1), (2, p2, expected.part2)] {
+        match (answer, expected) {
+            (Some(answer), Some(expected)) => assert_eq!(expected, answer, "part {}", part),
+            (Some(answer), None) => report_unchecked(day, filename, part, &answer),
+            (None, _) => {}
+        }
     }
     Ok(())
 }
 
-pub fn normal_day(
-    f: fn(&str) -> color_eyre::Result<(u64, u64)>,
-    day: usize,
-    part1: u64,
-    part2: u64,
-) -> color_eyre::Result<()> {
-    test_one_file(f, day, "input.txt", Some(part1), Some(part2))
+#[cfg(test)]
+fn report_unchecked(day: usize, filename: &str, part: usize, answer: &Answer) {
+    if *answer == Answer::None {
+        return;
+    }
+    println!(
+        "Day {} {} part {}: {} (no expected answer)",
+        day, filename, part, answer
+    );
+}
+
+#[derive(Debug)]
+pub struct Checked {
+    pub answers: (Answer, Answer),
+    pub expected: Answers,
+    pub phases: Option<Phases>,
+    pub heap: Option<HeapUsage>,
 }
 
-#[cfg(test)]
-pub mod test {
-    macro_rules! file_test {
-        ( $name: ident, $day: literal, $filename: literal, $part1: expr, $part2: expr) => {
-            #[test]
-            fn $name() {
-                crate::runner::test_one_file(run, $day, $filename, $part1, $part2).unwrap()
+impl Checked {
+    pub fn status(&self) -> Status {
+        let parts = [
+            (&self.answers.0, &self.expected.part1),
+            (&self.answers.1, &self.expected.part2),
+        ];
+        if parts
+            .iter()
+            .any(|(a, e)| e.as_ref().is_some_and(|e| e != *a))
+        {
+            Status::Fail
+        } else if parts
+            .iter()
+            .all(|(a, e)| e.is_some() || **a == Answer::None)
+        {
+            Status::Pass
+        } else {
+            Status::Unchecked
+        }
+    }
+}
+
+// Solves the day's input.txt and compares against its manifest without asserting.
+// With phases, parsing and each part are run and timed one after another.
+pub fn check_day(d: &Day, year: u32, day: usize, phases: bool) -> color_eyre::Result<Checked> {
+    let input = inputs::read(year, day, "input.txt")?;
+    let expected = Manifest::load(year, day)?.get("input.txt");
+    // A panicking solver only fails its own day
+    let (solved, heap) = heap::measure(|| {
+        panic::catch_unwind(|| -> color_eyre::Result<_> {
+            if phases {
+                let (answers, phases) = (d.timed)(&input)?;
+                Ok((answers, Some(phases)))
+            } else {
+                Ok(((d.run)(&input)?, None))
             }
-        };
+        })
+    });
+    let solved = solved.map_err(|payload| eyre!("Panicked: {}", panic_message(&*payload)))?;
+    let (answers, phases) = solved?;
+    Ok(Checked {
+        answers,
+        expected,
+        phases,
+        heap,
+    })
+}
+
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "unknown panic"
     }
-    pub(crate) use file_test;
+}
 
-    macro_rules! sample_test {
-        ( $name: ident, $day: literal, $part1: expr, $part2: expr) => {
-            crate::runner::test::file_test!($name, $day, "sample.txt", $part1, $part2);
-        };
+pub fn normal_day(d: &Day, year: u32, day: usize) -> color_eyre::Result<()> {
+    let checked = check_day(d, year, day, false)?;
+    if checked.status() == Status::Fail {
+        return Err(eyre!(
+            "Day {}: expected {:?}, got {:?}",
+            day,
+            checked.expected,
+            checked.answers
+        ));
     }
-    pub(crate) use sample_test;
+    Ok(())
+}
 
-    #[cfg(test)]
-    macro_rules! input_test {
-        ( $name: ident, $day: literal, $part1: expr, $part2: expr) => {
-            crate::runner::test::file_test!($name, $day, "input.txt", $part1, $part2);
-        };
+// Solves the day's input.txt and writes the answers into its manifest
+pub fn record_day(d: &Day, year: u32, day: usize) -> color_eyre::Result<(Answer, Answer)> {
+    let input = inputs::read(year, day, "input.txt")?;
+    let (p1, p2) = (d.run)(&input)?;
+    let mut manifest = Manifest::load(year, day)?;
+    manifest.set(
+        "input.txt",
+        Answers {
+            part1: p1.clone().known(),
+            part2: p2.clone().known(),
+        },
+    );
+    manifest.save(year, day)?;
+    Ok((p1, p2))
+}
+
+// Reads the whole of stdin if path is "-"
+pub fn read_input(path: &str) -> color_eyre::Result<String> {
+    if path == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
     }
-    pub(crate) use input_test;
+    Ok(std::fs::read_to_string(path)?)
+}
+
+// A test_file test for every input and sample file (or file in the answers manifest) of
+// each day with a solution, generated by build.rs
+#[cfg(test)]
+mod file_tests {
+    include!(concat!(env!("OUT_DIR"), "/file_tests.rs"));
 }
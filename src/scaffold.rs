@@ -0,0 +1,253 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::MAX_DAYS;
+
+const TEMPLATE: &str = "\
+use color_eyre::Result;
+
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+pub struct Day{N};
+
+impl Solution for Day{N} {
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(_lines: &Vec<&str>) -> Result<Answer> {
+        Ok(Answer::None)
+    }
+
+    fn part2(_lines: &Vec<&str>) -> Result<Answer> {
+        Ok(Answer::None)
+    }
+}
+";
+
+// A new year's module, before its first day is registered
+const YEAR_TEMPLATE: &str = "\
+use crate::solution::{Day, Year};
+
+pub const YEAR: Year = Year {
+    year: {Y},
+    days: &[
+    ],
+};
+";
+
+const YEARS_START: &str = "pub const YEARS: &[Year] = &[";
+
+// Creates src/year<Y>/day<N>.rs from the template and registers it in the year's
+// module, creating that (and registering it in lib.rs) for a new year. Days are
+// looked up by position in the year's days, so they have to be added in order.
+pub fn new_day(year: u32, day: usize, registered: usize) -> Result<()> {
+    if day > MAX_DAYS {
+        return Err(eyre!("{} only has {} days", year, MAX_DAYS));
+    }
+    if day != registered + 1 {
+        return Err(eyre!(
+            "Days are added in order, so the next one is {}",
+            registered + 1
+        ));
+    }
+    let path = source_path(year, day);
+    let year_dir = path.parent().unwrap();
+    if path.exists() {
+        return Err(eyre!("{} already exists", path.display()));
+    }
+
+    let module_path = year_dir.join("mod.rs");
+    let lib_path = src_dir().join("lib.rs");
+    let mut lib = None;
+    let module = if module_path.exists() {
+        std::fs::read_to_string(&module_path)?
+    } else {
+        lib = Some(register_year(&std::fs::read_to_string(&lib_path)?, year)?);
+        YEAR_TEMPLATE.replace("{Y}", &year.to_string())
+    };
+    let module = register_day(&module, day)?;
+
+    std::fs::create_dir_all(year_dir)?;
+    std::fs::write(&path, TEMPLATE.replace("{N}", &day.to_string()))?;
+    std::fs::write(&module_path, module)?;
+    if let Some(lib) = lib {
+        std::fs::write(&lib_path, lib)?;
+        println!("Added {} to YEARS", year);
+    }
+    println!("Created {} and added it to {}", path.display(), year);
+    Ok(())
+}
+
+pub fn src_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src")
+}
+
+// src/year<Y>/day<N>.rs
+pub fn source_path(year: u32, day: usize) -> PathBuf {
+    src_dir()
+        .join(format!("year{}", year))
+        .join(format!("day{}.rs", day))
+}
+
+// Adds the mod declaration to a block of them (or before the line starting with
+// `before` if there are none yet), keeping them sorted like rustfmt does
+fn add_mod(lines: &mut Vec<String>, prefix: &str, name: String, before: &str) -> Result<()> {
+    let is_mod = |l: &String| l.starts_with(prefix) && l.ends_with(';');
+    match lines.iter().position(is_mod) {
+        Some(first) => {
+            let count = lines[first..].iter().take_while(|l| is_mod(l)).count();
+            lines.insert(first + count, name);
+            lines[first..=first + count].sort_by_key(|l| l.trim_end_matches(';').to_string());
+        }
+        None => {
+            let i = lines
+                .iter()
+                .position(|l| l.starts_with(before))
+                .ok_or_else(|| eyre!("Nowhere to add {}", name))?;
+            lines.splice(i..i, [name, String::new()]);
+        }
+    }
+    Ok(())
+}
+
+// Adds the day's mod declaration and its entry at the end of the year's days
+fn register_day(module: &str, day: usize) -> Result<String> {
+    let mut lines: Vec<String> = module.lines().map(str::to_string).collect();
+    add_mod(
+        &mut lines,
+        "pub mod day",
+        format!("pub mod day{};", day),
+        "pub const YEAR",
+    )?;
+
+    let start = lines
+        .iter()
+        .position(|l| l.starts_with("    days: &["))
+        .ok_or_else(|| eyre!("No days list in the year's module"))?;
+    // rustfmt puts a single day on one line
+    if let Some(days) = lines[start]
+        .strip_prefix("    days: &[")
+        .and_then(|l| l.strip_suffix("],"))
+    {
+        let days: Vec<String> = days
+            .split(", ")
+            .filter(|d| !d.is_empty())
+            .map(|d| format!("        {},", d))
+            .collect();
+        let end = ["    days: &[".to_string()]
+            .into_iter()
+            .chain(days)
+            .chain(["    ],".to_string()]);
+        lines.splice(start..=start, end);
+    }
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l == "    ],")
+            .ok_or_else(|| eyre!("Days list isn't closed"))?;
+    lines.insert(end, format!("        Day::of::<day{0}::Day{0}>(),", day));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Adds the year's mod declaration and its entry in YEARS, which is kept in order so
+// the newest year is the default
+fn register_year(lib: &str, year: u32) -> Result<String> {
+    let mut lines: Vec<String> = lib.lines().map(str::to_string).collect();
+    add_mod(
+        &mut lines,
+        "pub mod year",
+        format!("pub mod year{};", year),
+        "pub use ",
+    )?;
+
+    let i = lines
+        .iter()
+        .position(|l| l.starts_with(YEARS_START) && l.ends_with("];"))
+        .ok_or_else(|| eyre!("No one-line YEARS list in lib.rs"))?;
+    let mut years: Vec<String> = lines[i][YEARS_START.len()..lines[i].len() - 2]
+        .split(',')
+        .map(|y| y.trim().to_string())
+        .filter(|y| !y.is_empty())
+        .collect();
+    years.push(format!("year{}::YEAR", year));
+    years.sort();
+    lines[i] = format!("{}{}];", YEARS_START, years.join(", "));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_days() -> Result<()> {
+        let module =
+            "use crate::solution::{Day, Year};\n\npub mod day1;\npub mod day10;\npub mod day2;\n\n\
+                      pub const YEAR: Year = Year {\n    year: 2023,\n    days: &[\n        \
+                      Day::of::<day1::Day1>(),\n    ],\n};\n";
+        assert_eq!(
+            "use crate::solution::{Day, Year};\n\npub mod day1;\npub mod day10;\npub mod day11;\npub mod day2;\n\n\
+             pub const YEAR: Year = Year {\n    year: 2023,\n    days: &[\n        \
+             Day::of::<day1::Day1>(),\n        Day::of::<day11::Day11>(),\n    ],\n};\n",
+            register_day(module, 11)?
+        );
+        assert!(register_day("fn main() {}\n", 1).is_err());
+
+        let module = "pub mod day1;\n\npub const YEAR: Year = Year {\n    year: 2024,\n    \
+                      days: &[Day::of::<day1::Day1>()],\n};\n";
+        assert_eq!(
+            "pub mod day1;\npub mod day2;\n\npub const YEAR: Year = Year {\n    year: 2024,\n    \
+             days: &[\n        Day::of::<day1::Day1>(),\n        Day::of::<day2::Day2>(),\n    \
+             ],\n};\n",
+            register_day(module, 2)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stops_at_the_last_day() {
+        let err = new_day(2023, 26, 25).unwrap_err();
+        assert_eq!("2023 only has 25 days", err.to_string());
+    }
+
+    #[test]
+    fn registers_days_in_new_years() -> Result<()> {
+        let module = register_day(&YEAR_TEMPLATE.replace("{Y}", "2024"), 1)?;
+        assert_eq!(
+            "use crate::solution::{Day, Year};\n\npub mod day1;\n\n\
+             pub const YEAR: Year = Year {\n    year: 2024,\n    days: &[\n        \
+             Day::of::<day1::Day1>(),\n    ],\n};\n",
+            module
+        );
+
+        let lib = register_year(include_str!("lib.rs"), 2024)?;
+        assert!(lib.contains("\npub mod year2023;\npub mod year2024;\n"));
+        assert!(lib.contains("const YEARS: &[Year] = &[year2023::YEAR, year2024::YEAR];\n"));
+        let lib = register_year(&lib, 2015)?;
+        assert!(lib.contains("\npub mod year2015;\npub mod year2023;\npub mod year2024;\n"));
+        assert!(lib.contains(
+            "const YEARS: &[Year] = &[year2015::YEAR, year2023::YEAR, year2024::YEAR];\n"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn registers_days_in_current_year() -> Result<()> {
+        let day = crate::year2023::YEAR.days.len() + 1;
+        let module = register_day(include_str!("year2023/mod.rs"), day)?;
+        assert!(module.contains(&format!("\npub mod day{};\n", day)));
+        assert!(module.contains(&format!(
+            "        Day::of::<day{0}::Day{0}>(),\n        Day::of::<day{1}::Day{1}>(),\n    ],",
+            day - 1,
+            day
+        )));
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use std::{collections::BTreeSet, str::FromStr};
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::MAX_DAYS;
+
+// Days given on the command line as a comma separated list of days and inclusive
+// ranges, e.g. "1-5,12,20-25"
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DaySet(BTreeSet<usize>);
+
+impl DaySet {
+    pub fn contains(&self, day: usize) -> bool {
+        self.0.contains(&day)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.0.iter().copied()
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = color_eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut days = BTreeSet::new();
+        for item in s.split(',').map(str::trim) {
+            // Checked here rather than later, so a huge range is never expanded
+            let parse = |n: &str| match n.trim().parse::<usize>() {
+                Ok(day) if (1..=MAX_DAYS).contains(&day) => Ok(day),
+                Ok(day) => Err(eyre!("Day {} isn't between 1 and {}", day, MAX_DAYS)),
+                Err(_) => Err(eyre!("Invalid day {:?} in {:?}", n, s)),
+            };
+            match item.split_once('-') {
+                Some((from, to)) => {
+                    let (from, to) = (parse(from)?, parse(to)?);
+                    if from > to {
+                        return Err(eyre!("Empty day range {}", item));
+                    }
+                    days.extend(from..=to);
+                }
+                None => {
+                    days.insert(parse(item)?);
+                }
+            }
+        }
+        Ok(Self(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_days_and_ranges() -> Result<()> {
+        let days: DaySet = "1-5,12, 20-25".parse()?;
+        assert_eq!(
+            vec![1, 2, 3, 4, 5, 12, 20, 21, 22, 23, 24, 25],
+            days.iter().collect::<Vec<_>>()
+        );
+        assert!(days.contains(23));
+        assert!(!days.contains(6));
+
+        let days: DaySet = "23,3-3,23".parse()?;
+        assert_eq!(vec![3, 23], days.iter().collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_bad_selections() {
+        assert!("".parse::<DaySet>().is_err());
+        assert!("1,,2".parse::<DaySet>().is_err());
+        assert!("5-1".parse::<DaySet>().is_err());
+        assert!("1-".parse::<DaySet>().is_err());
+        assert!("day3".parse::<DaySet>().is_err());
+    }
+
+    #[test]
+    fn rejects_days_out_of_range() {
+        assert!("0".parse::<DaySet>().is_err());
+        assert!("26".parse::<DaySet>().is_err());
+        assert!("20-26".parse::<DaySet>().is_err());
+        let err = "1-100000000000".parse::<DaySet>().unwrap_err();
+        assert_eq!("Day 100000000000 isn't between 1 and 25", err.to_string());
+    }
+}
@@ -0,0 +1,134 @@
+use std::time::{Duration, Instant};
+
+use color_eyre::Result;
+
+use crate::answers::Answer;
+use crate::heap::{self, HeapUsage};
+
+// A day's puzzle split into phases, so the runner can time each one or run a single part.
+// Work that both parts share (e.g. tracing day 10's loop) belongs in parse.
+pub trait Solution {
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    // Days can override this, e.g. to solve both parts in parallel
+    fn run(input: &str) -> Result<(Answer, Answer)> {
+        let parsed = Self::parse(input)?;
+        Ok((Self::part1(&parsed)?, Self::part2(&parsed)?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Phases {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+    // Only measured with the dhat-heap feature
+    pub parse_heap: Option<HeapUsage>,
+    pub part1_heap: Option<HeapUsage>,
+    pub part2_heap: Option<HeapUsage>,
+}
+
+type Solver<T> = fn(&str) -> Result<T>;
+
+// Solves one part (1 or 2) of an already parsed input
+pub type ParsedPart<'a> = &'a dyn Fn(usize) -> Result<Answer>;
+
+type WithParsed = fn(&str, &mut dyn FnMut(ParsedPart)) -> Result<()>;
+
+// A year's solutions, with days looked up by position
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    // Days are numbered from 1
+    pub fn day(&self, day: usize) -> Option<&Day> {
+        self.days.get(day.checked_sub(1)?)
+    }
+}
+
+// One day's entry points with the parsed type erased, so all days fit in one table
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub run: Solver<(Answer, Answer)>,
+    pub timed: Solver<((Answer, Answer), Phases)>,
+    pub part1: Solver<Answer>,
+    pub part2: Solver<Answer>,
+    // Parses the input once and hands f the parts over it, e.g. to benchmark them
+    // without parsing each time
+    pub with_parsed: WithParsed,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            run: S::run,
+            timed: timed::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            with_parsed: with_parsed::<S>,
+        }
+    }
+
+    // Parses and solves just the given part (1 or 2)
+    pub fn part(&self, part: usize) -> Option<Solver<Answer>> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+}
+
+// Runs the phases one after another, so days that normally solve both parts in
+// parallel take longer in total here
+fn timed<S: Solution>(input: &str) -> Result<((Answer, Answer), Phases)> {
+    let start = Instant::now();
+    let (parsed, parse_heap) = heap::measure(|| S::parse(input));
+    let parsed = parsed?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let (p1, part1_heap) = heap::measure(|| S::part1(&parsed));
+    let p1 = p1?;
+    let part1 = start.elapsed();
+
+    let start = Instant::now();
+    let (p2, part2_heap) = heap::measure(|| S::part2(&parsed));
+    let p2 = p2?;
+    let part2 = start.elapsed();
+
+    Ok((
+        (p1, p2),
+        Phases {
+            parse,
+            part1,
+            part2,
+            parse_heap,
+            part1_heap,
+            part2_heap,
+        },
+    ))
+}
+
+fn part1<S: Solution>(input: &str) -> Result<Answer> {
+    S::part1(&S::parse(input)?)
+}
+
+fn part2<S: Solution>(input: &str) -> Result<Answer> {
+    S::part2(&S::parse(input)?)
+}
+
+fn with_parsed<S: Solution>(input: &str, f: &mut dyn FnMut(ParsedPart)) -> Result<()> {
+    let parsed = S::parse(input)?;
+    f(&|part| match part {
+        1 => S::part1(&parsed),
+        _ => S::part2(&parsed),
+    });
+    Ok(())
+}
@@ -0,0 +1,306 @@
+use std::{
+    fmt::Display,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use color_eyre::{eyre::eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::answers::{Answer, Manifest};
+use crate::fetch::{strip_tags, unescape, Client};
+use crate::inputs;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    // The part was solved some other way, so the answer wasn't checked
+    AlreadySolved,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::AlreadySolved => "already solved",
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Response {
+    Verdict(Verdict),
+    // Submitted too soon after the last answer, with the seconds left to wait
+    TooSoon(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: usize,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    // Seconds since the Unix epoch
+    pub at: u64,
+}
+
+// Everything submitted for one day, kept in inputs/<year>/<day>/submissions.toml
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    // The server won't take another answer before this (seconds since the Unix epoch)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_until: Option<u64>,
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    pub fn load(year: u32, day: usize) -> Result<Self> {
+        match inputs::read_from_disk(year, day, "submissions.toml")? {
+            Some(s) => Ok(toml::from_str(&s)?),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn save(&self, year: u32, day: usize) -> Result<()> {
+        inputs::write(year, day, "submissions.toml", &toml::to_string(self)?)
+    }
+
+    // Refuses answers that can't be right (or can't be sent yet) going by earlier ones
+    fn check(&self, part: usize, answer: &Answer, now: u64) -> Result<()> {
+        if let Answer::None = answer {
+            return Err(eyre!("Part {} has no answer to submit", part));
+        }
+        let earlier = self.submissions.iter().filter(|s| s.part == part);
+        for s in earlier {
+            if s.verdict == Verdict::Correct {
+                return Err(eyre!("Part {} was already solved with {}", part, s.answer));
+            }
+            if s.answer == *answer {
+                return Err(eyre!("{} was already submitted: {}", answer, s.verdict));
+            }
+            if let (Answer::Int(n), Answer::Int(prev)) = (answer, &s.answer) {
+                if (s.verdict == Verdict::TooHigh && n > prev)
+                    || (s.verdict == Verdict::TooLow && n < prev)
+                {
+                    return Err(eyre!("{} is {}: {} already was", answer, s.verdict, prev));
+                }
+            }
+        }
+        match self.wait_until {
+            Some(t) if t > now => Err(eyre!("Submitted too recently, wait another {}s", t - now)),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+// Submits one part's answer unless the history rules it out, recording the verdict
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u32,
+    day: usize,
+    part: usize,
+    answer: &Answer,
+    now: u64,
+) -> Result<Verdict> {
+    history.check(part, answer, now)?;
+    let page = client.submit(year, day, part, &answer.to_string())?;
+    let text = unescape(&strip_tags(&page));
+    match parse_response(&text)? {
+        Response::Verdict(verdict) => {
+            history.wait_until = parse_wait(&text).map(|wait| now + wait);
+            history.submissions.push(Submission {
+                part,
+                answer: answer.clone(),
+                verdict,
+                at: now,
+            });
+            Ok(verdict)
+        }
+        Response::TooSoon(wait) => {
+            history.wait_until = Some(now + wait);
+            Err(eyre!("Submitted too recently, wait another {}s", wait))
+        }
+    }
+}
+
+// Submits the answer for the day's input.txt, saving the history and recording a
+// correct answer in the day's manifest
+pub fn submit_day(
+    client: &Client,
+    year: u32,
+    day: usize,
+    part: usize,
+    answer: &Answer,
+) -> Result<Verdict> {
+    let mut history = History::load(year, day)?;
+    let result = submit(client, &mut history, year, day, part, answer, now());
+    history.save(year, day)?;
+    let verdict = result?;
+    if verdict == Verdict::Correct {
+        let mut manifest = Manifest::load(year, day)?;
+        let mut answers = manifest.get("input.txt");
+        match part {
+            1 => answers.part1 = Some(answer.clone()),
+            _ => answers.part2 = Some(answer.clone()),
+        }
+        manifest.set("input.txt", answers);
+        manifest.save(year, day)?;
+    }
+    Ok(verdict)
+}
+
+fn parse_response(text: &str) -> Result<Response> {
+    if text.contains("That's the right answer") {
+        Ok(Response::Verdict(Verdict::Correct))
+    } else if text.contains("That's not the right answer") {
+        Ok(Response::Verdict(if text.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        }))
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Response::TooSoon(parse_wait(text).unwrap_or(60)))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Response::Verdict(Verdict::AlreadySolved))
+    } else {
+        Err(eyre!("Couldn't understand the response: {}", text.trim()))
+    }
+}
+
+// "You have 4m 20s left to wait", or "please wait one minute" / "wait 5 minutes"
+// after a wrong answer
+fn parse_wait(text: &str) -> Option<u64> {
+    if let Some(i) = text.find(" left to wait") {
+        let start = text[..i].rfind("You have ")? + "You have ".len();
+        return text[start..i]
+            .split_whitespace()
+            .map(|t| {
+                let (n, unit) = t.split_at(t.len() - 1);
+                let n: u64 = n.parse().ok()?;
+                match unit {
+                    "h" => Some(n * 3600),
+                    "m" => Some(n * 60),
+                    "s" => Some(n),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+    let i = text.find("wait ")? + "wait ".len();
+    let mut words = text[i..].split_whitespace();
+    let n = match words.next()? {
+        "one" | "a" => 1,
+        n => n.parse().ok()?,
+    };
+    match words.next()?.trim_end_matches(|c: char| !c.is_alphabetic()) {
+        "minute" | "minutes" => Some(n * 60),
+        "second" | "seconds" => Some(n),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::mock::serve;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer! You are <span \
+                           class=\"day-success\">one gold star</span> closer.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+                            high.  If you're stuck, make sure you're using the full input data. \
+                            Please wait one minute before trying again.</p></article></main>";
+    const TOO_SOON: &str = "<main><article><p>You gave an answer too recently; you have to wait \
+                            after submitting an answer before trying again.  You have 4m 20s \
+                            left to wait.</p></article></main>";
+
+    #[test]
+    fn parses_responses() -> Result<()> {
+        let parse = |page| parse_response(&unescape(&strip_tags(page)));
+        assert_eq!(Response::Verdict(Verdict::Correct), parse(CORRECT)?);
+        assert_eq!(Response::Verdict(Verdict::TooHigh), parse(TOO_HIGH)?);
+        assert_eq!(Response::TooSoon(260), parse(TOO_SOON)?);
+        assert_eq!(
+            Response::Verdict(Verdict::TooLow),
+            parse("That's not the right answer; your answer is too low. Please wait 5 minutes")?
+        );
+        assert_eq!(
+            Some(300),
+            parse_wait("That's not the right answer. Please wait 5 minutes before trying again.")
+        );
+        assert!(parse("<p>Something else</p>").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let history = History {
+            wait_until: Some(1060),
+            submissions: vec![
+                Submission {
+                    part: 1,
+                    answer: Answer::Int(500),
+                    verdict: Verdict::TooHigh,
+                    at: 1000,
+                },
+                Submission {
+                    part: 1,
+                    answer: Answer::Int(100),
+                    verdict: Verdict::TooLow,
+                    at: 900,
+                },
+            ],
+        };
+        assert!(history.check(1, &Answer::Int(500), 2000).is_err());
+        assert!(history.check(1, &Answer::Int(501), 2000).is_err());
+        assert!(history.check(1, &Answer::Int(99), 2000).is_err());
+        assert!(history.check(1, &Answer::Int(300), 1030).is_err());
+        assert!(history.check(1, &Answer::Int(300), 2000).is_ok());
+        assert!(history.check(2, &Answer::Int(500), 2000).is_ok());
+        assert!(history.check(2, &Answer::None, 2000).is_err());
+    }
+
+    #[test]
+    fn submits_and_records_verdicts() -> Result<()> {
+        let (base_url, server) = serve(vec![(200, TOO_HIGH), (200, TOO_SOON), (200, CORRECT)]);
+        let client = Client::new(&base_url, "s3cret");
+        let mut history = History::default();
+
+        let verdict = submit(&client, &mut history, 2023, 3, 1, &Answer::Int(600), 1000)?;
+        assert_eq!(Verdict::TooHigh, verdict);
+        assert_eq!(Some(1060), history.wait_until);
+
+        // Checked locally, so these never reach the server
+        assert!(submit(&client, &mut history, 2023, 3, 1, &Answer::Int(600), 2000).is_err());
+        assert!(submit(&client, &mut history, 2023, 3, 1, &Answer::Int(550), 1010).is_err());
+
+        assert!(submit(&client, &mut history, 2023, 3, 1, &Answer::Int(550), 1070).is_err());
+        assert_eq!(Some(1330), history.wait_until);
+        let verdict = submit(&client, &mut history, 2023, 3, 1, &Answer::Int(550), 1400)?;
+        assert_eq!(Verdict::Correct, verdict);
+        assert_eq!(None, history.wait_until);
+        assert_eq!(2, history.submissions.len());
+
+        let reparsed: History = toml::from_str(&toml::to_string(&history)?)?;
+        assert_eq!(history, reparsed);
+
+        let requests = server.join().unwrap();
+        assert_eq!("POST /2023/day/3/answer HTTP/1.1", requests[0].0[0]);
+        assert_eq!("level=1&answer=600", requests[0].1);
+        Ok(())
+    }
+}
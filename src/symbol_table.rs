@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+#[derive(Default)]
 pub struct SymbolTable {
     symbols: HashMap<String, u16>,
     next_id: u16,
@@ -27,4 +28,8 @@ impl SymbolTable {
     pub fn len(&self) -> usize {
         self.next_id as usize
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.next_id == 0
+    }
 }
@@ -0,0 +1,185 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant, SystemTime},
+};
+
+use color_eyre::{eyre::eyre, Result};
+
+use crate::answers::{Answer, Manifest};
+use crate::inputs;
+use crate::report::format_runtime_duration;
+use crate::runner::panic_message;
+use crate::scaffold::src_dir;
+use crate::solution::{Day, Year};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// Reruns the day on its input and every sample whenever one of them or the answers
+// manifest is added or changed. When the source changes, rebuilds and restarts with
+// the new binary.
+pub fn watch(year: &Year, day: usize) -> Result<()> {
+    let d = year
+        .day(day)
+        .ok_or_else(|| eyre!("No solution for day {}", day))?;
+    // Looked up before rebuilding, as Linux reports a replaced binary as deleted
+    let exe = std::env::current_exe()?;
+    let mut files = (vec![], None);
+    let mut sources = modified_times(&src_dir());
+    println!("Watching day {} (Ctrl-C to stop)", day);
+    loop {
+        let latest = (
+            read_files(year.year, day)?,
+            inputs::modified(year.year, day, "answers.toml"),
+        );
+        if latest != files {
+            files = latest;
+            run_files(d, year.year, day, &files.0);
+        }
+        let latest = modified_times(&src_dir());
+        if latest != sources {
+            sources = latest;
+            println!("Source changed, rebuilding");
+            match rebuild() {
+                Ok(()) => return restart(&exe),
+                Err(e) => println!("{}", e),
+            }
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+// The day's input and samples, as (filename, contents)
+fn read_files(year: u32, day: usize) -> Result<Vec<(String, String)>> {
+    inputs::puzzle_files(year, day)?
+        .into_iter()
+        .map(|f| {
+            let contents = inputs::read(year, day, &f)?;
+            Ok((f, contents))
+        })
+        .collect()
+}
+
+fn run_files(d: &Day, year: u32, day: usize, files: &[(String, String)]) {
+    if files.is_empty() {
+        println!("Day {}: no input or samples yet", day);
+        return;
+    }
+    println!();
+    // A half edited manifest shouldn't stop the watch, so it's run without answers
+    let manifest = Manifest::load(year, day).unwrap_or_else(|e| {
+        let e = e.to_string();
+        println!("Day {} answers.toml:\tERROR: {}", day, e.trim_end());
+        Manifest::default()
+    });
+    for (filename, input) in files {
+        let expected = manifest.get(filename);
+        let start = Instant::now();
+        // Like the tests, only one part is solved for single part samples
+        let solved = std::panic::catch_unwind(|| match inputs::only_part(filename) {
+            Some(1) => Ok((Some((d.part1)(input)?), None)),
+            Some(_) => Ok((None, Some((d.part2)(input)?))),
+            None => (d.run)(input).map(|(p1, p2)| (Some(p1), Some(p2))),
+        });
+        match solved {
+            Ok(Ok((p1, p2))) => println!(
+                "Day {} {}:\tpart 1 {}\tpart 2 {}\t({})",
+                day,
+                filename,
+                describe(p1, &expected.part1),
+                describe(p2, &expected.part2),
+                format_runtime_duration(&start.elapsed())
+            ),
+            Ok(Err(e)) => println!("Day {} {}:\tERROR: {}", day, filename, e),
+            Err(payload) => println!(
+                "Day {} {}:\tPANICKED: {}",
+                day,
+                filename,
+                panic_message(&*payload)
+            ),
+        }
+    }
+}
+
+fn describe(answer: Option<Answer>, expected: &Option<Answer>) -> String {
+    match (answer, expected) {
+        (None, _) => "not solved".to_string(),
+        (Some(a), Some(e)) if a == *e => format!("{} (ok)", a),
+        (Some(a), Some(e)) => format!("{} (expected {})", a, e),
+        (Some(a), None) => a.to_string(),
+    }
+}
+
+fn modified_times(dir: &Path) -> Vec<(PathBuf, SystemTime)> {
+    let mut times = vec![];
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return times;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            times.extend(modified_times(&path));
+        } else if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+            times.push((path, modified));
+        }
+    }
+    times.sort();
+    times
+}
+
+// Builds with the same profile and features as this binary
+fn rebuild() -> Result<()> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command
+        .arg("build")
+        .arg("--manifest-path")
+        .arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let features: Vec<&str> = [
+        (cfg!(feature = "dhat-heap"), "dhat-heap"),
+        (cfg!(feature = "dhat-ad-hoc"), "dhat-ad-hoc"),
+        (cfg!(feature = "embed-inputs"), "embed-inputs"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, feature)| enabled.then_some(feature))
+    .collect();
+    if !features.is_empty() {
+        command.arg("--features").arg(features.join(","));
+    }
+    if !command.status()?.success() {
+        return Err(eyre!("Build failed, watching with the old build"));
+    }
+    Ok(())
+}
+
+// Replaces this process with the rebuilt binary and the same arguments
+fn restart(exe: &Path) -> Result<()> {
+    let mut command = Command::new(exe);
+    command.args(std::env::args_os().skip(1));
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        Err(command.exec().into())
+    }
+    #[cfg(not(unix))]
+    {
+        std::process::exit(command.status()?.code().unwrap_or(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_answers_against_expectations() {
+        let four = Some(Answer::Int(4));
+        assert_eq!("4 (ok)", describe(four.clone(), &four));
+        assert_eq!("5 (expected 4)", describe(Some(Answer::Int(5)), &four));
+        assert_eq!("5", describe(Some(Answer::Int(5)), &None));
+        assert_eq!("not solved", describe(None, &four));
+    }
+}
@@ -1,21 +1,42 @@
 use rayon::prelude::*;
 
+use crate::answers::Answer;
+use crate::solution::Solution;
+
 const DIGITS: [&str; 10] = [
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-pub fn run(input: &str) -> color_eyre::Result<(u64, u64)> {
-    let (p1, p2) = rayon::join(|| part1(input), || part2(input));
-    Ok((p1? as u64, p2? as u64))
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = &'a str;
+
+    fn parse(input: &str) -> color_eyre::Result<&str> {
+        Ok(input)
+    }
+
+    fn part1(input: &&str) -> color_eyre::Result<Answer> {
+        Ok(part1(input)?.into())
+    }
+
+    fn part2(input: &&str) -> color_eyre::Result<Answer> {
+        Ok(part2(input)?.into())
+    }
+
+    fn run(input: &str) -> color_eyre::Result<(Answer, Answer)> {
+        let (p1, p2) = rayon::join(|| part1(input), || part2(input));
+        Ok((p1?.into(), p2?.into()))
+    }
 }
 
 fn part1(input: &str) -> color_eyre::Result<u32> {
     let sum = input
         .par_lines()
         .map(|l| {
-            let digits = l.chars().filter_map(|c| c.to_digit(10));
+            let mut digits = l.chars().filter_map(|c| c.to_digit(10));
             let first = digits.clone().next().unwrap();
-            let last = digits.last().unwrap();
+            let last = digits.next_back().unwrap();
 
             first * 10 + last
         })
@@ -59,20 +80,3 @@ fn leading_digit(s: &str) -> Option<u32> {
     }
     None
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::runner::test::{input_test, sample_test};
-
-    use super::*;
-
-    sample_test!(sample_part1, 1, Some(142), None);
-    input_test!(real, 1, Some(55816), Some(54980));
-
-    #[test]
-    fn sample_part2() -> color_eyre::Result<()> {
-        let input = std::fs::read_to_string("inputs/1/sample_part2.txt")?;
-        assert_eq!(281, part2(&input)?);
-        Ok(())
-    }
-}
@@ -0,0 +1,89 @@
+use color_eyre::{eyre::eyre, Result};
+
+use crate::answers::Answer;
+use crate::grid::{Dir, Grid};
+use crate::solution::Solution;
+
+// The two directions a pipe leads in
+fn pipe_ends(pipe_char: char) -> Option<[Dir; 2]> {
+    match pipe_char {
+        '|' => Some([Dir::North, Dir::South]),
+        '-' => Some([Dir::East, Dir::West]),
+        'L' => Some([Dir::North, Dir::East]),
+        'J' => Some([Dir::North, Dir::West]),
+        '7' => Some([Dir::South, Dir::West]),
+        'F' => Some([Dir::South, Dir::East]),
+        _ => None,
+    }
+}
+
+// The main loop, found while parsing as both parts need it
+pub struct PipeLoop {
+    length: u64,
+    shoelace_sum: i64,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = PipeLoop;
+
+    fn parse(input: &str) -> Result<PipeLoop> {
+        trace_loop(&Grid::try_parse(input, |c| c)?)
+    }
+
+    fn part1(pipe_loop: &PipeLoop) -> Result<Answer> {
+        Ok((pipe_loop.length / 2).into())
+    }
+
+    fn part2(pipe_loop: &PipeLoop) -> Result<Answer> {
+        // Pick's formula
+        Ok((pipe_loop.shoelace_sum.unsigned_abs() / 2 - (pipe_loop.length / 2) + 1).into())
+    }
+}
+
+fn trace_loop(grid: &Grid<char>) -> Result<PipeLoop> {
+    let start = grid.find(|&c| c == 'S').ok_or_else(|| eyre!("No start"))?;
+    // Find the adjacent pipes with the correct orientation
+    let first_pipes = grid
+        .neighbors4(start.0, start.1)
+        .filter(|&(dir, (x, y))| {
+            pipe_ends(*grid.at(x, y)).is_some_and(|ends| ends.contains(&dir.reverse()))
+        })
+        .collect::<Vec<_>>();
+    if first_pipes.len() != 2 {
+        return Err(eyre!(
+            "The start connects to {} pipes rather than 2",
+            first_pipes.len()
+        ));
+    }
+    let ((mut dir, mut next), mut prev) = (first_pipes[0], start);
+
+    let mut count = 1;
+    let mut shoelace_sum: i64 = 0;
+    while next != start {
+        shoelace_sum += prev.0 as i64 * next.1 as i64 - prev.1 as i64 * next.0 as i64;
+        count += 1;
+        let pipe_char = *grid.at(next.0, next.1);
+        dir = follow_pipe(pipe_char, dir)
+            .ok_or_else(|| eyre!("Can't go {:?} through {:?} at {:?}", dir, pipe_char, next))?;
+        let after = grid
+            .step(next.0, next.1, dir)
+            .ok_or_else(|| eyre!("The pipe at {:?} leads off the grid", next))?;
+        (next, prev) = (after, next);
+    }
+    shoelace_sum += prev.0 as i64 * next.1 as i64 - prev.1 as i64 * next.0 as i64;
+    Ok(PipeLoop {
+        length: count,
+        shoelace_sum,
+    })
+}
+
+// The direction to leave a pipe in, when entering it going in the given direction
+fn follow_pipe(pipe_char: char, dir: Dir) -> Option<Dir> {
+    match pipe_ends(pipe_char) {
+        Some([a, b]) if a == dir.reverse() => Some(b),
+        Some([a, b]) if b == dir.reverse() => Some(a),
+        _ => None,
+    }
+}
@@ -1,9 +1,12 @@
 use color_eyre::Result;
 
+use crate::answers::Answer;
+use crate::solution::Solution;
+
 type Point = (usize, usize);
 
 #[derive(Debug)]
-struct Universe {
+pub struct Universe {
     galaxies: Vec<Point>,
     dimensions: (usize, usize),
 }
@@ -41,10 +44,28 @@ impl Universe {
     }
 }
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let universe = parse(input)?;
-    let (p1, p2) = rayon::join(|| solve(&universe, 2), || solve(&universe, 1_000_000));
-    Ok((p1, p2))
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Universe;
+
+    fn parse(input: &str) -> Result<Universe> {
+        parse(input)
+    }
+
+    fn part1(universe: &Universe) -> Result<Answer> {
+        Ok(solve(universe, 2).into())
+    }
+
+    fn part2(universe: &Universe) -> Result<Answer> {
+        Ok(solve(universe, 1_000_000).into())
+    }
+
+    fn run(input: &str) -> Result<(Answer, Answer)> {
+        let universe = parse(input)?;
+        let (p1, p2) = rayon::join(|| solve(&universe, 2), || solve(&universe, 1_000_000));
+        Ok((p1.into(), p2.into()))
+    }
 }
 
 fn solve(universe: &Universe, expansion: usize) -> u64 {
@@ -81,16 +102,10 @@ fn parse(input: &str) -> Result<Universe> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 11, Some(374), None);
-    sample_test!(sample_part2, 11, None, Some(82000210));
-    input_test!(part1, 11, Some(9329143), None);
-    input_test!(part2, 11, None, Some(710674907809));
 
     #[test]
     fn sample_part2_at_different_scales() -> color_eyre::Result<()> {
-        let input = std::fs::read_to_string("inputs/11/sample.txt")?;
+        let input = crate::inputs::read(2023, 11, "sample.txt")?;
         let universe = parse(&input)?;
         assert_eq!(1030, solve(&universe, 10));
         assert_eq!(8410, solve(&universe, 100));
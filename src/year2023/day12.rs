@@ -10,24 +10,46 @@ use nom::{
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 
+use crate::answers::Answer;
+use crate::solution::Solution;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-enum Spring {
+pub enum Spring {
     Good,
     Bad,
     Unknown,
 }
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let parsed: Vec<(_, _)> = input
-        .lines()
-        .map(|l| parse_line(l).unwrap())
-        .map(|(_, (s, g))| (s, g))
-        .collect();
-    let p1 = parsed
-        .iter()
-        .map(|(s, g)| count_combinations_cached(s, g, &mut Cache::new()))
-        .sum();
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Vec<(Vec<Spring>, Vec<u8>)>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>> {
+        input
+            .lines()
+            .map(|l| {
+                let (_, (s, g)) =
+                    parse_line(l).map_err(|e| color_eyre::eyre::anyhow!("Parse error: {}", e))?;
+                Ok((s, g))
+            })
+            .collect()
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(parsed
+            .iter()
+            .map(|(s, g)| count_combinations_cached(s, g, &mut Cache::new()))
+            .sum::<u64>()
+            .into())
+    }
 
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer> {
+        Ok(part2(parsed)?.into())
+    }
+}
+
+fn part2(parsed: &[(Vec<Spring>, Vec<u8>)]) -> Result<u64> {
     let p2_parsed: Vec<_> = parsed
         .iter()
         .map(|(s, g)| {
@@ -50,7 +72,7 @@ pub fn run(input: &str) -> Result<(u64, u64)> {
         .map(|(s, g)| count_combinations_cached(s, g, &mut Cache::new()))
         .sum();
 
-    Ok((p1, p2))
+    Ok(p2)
 }
 
 #[derive(Hash, PartialEq, Eq)]
@@ -169,12 +191,6 @@ fn parse_line(input: &str) -> IResult<&str, (Vec<Spring>, Vec<u8>)> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 12, Some(21), None);
-    sample_test!(sample_part2, 12, None, Some(525152));
-    input_test!(part1, 12, Some(6852), None);
-    input_test!(part2, 12, None, Some(8475948826693));
 
     #[test]
     fn test_weird_case() {
@@ -0,0 +1,108 @@
+use color_eyre::{eyre::eyre, Result};
+use rayon::iter::{IntoParallelRefIterator, ParallelBridge, ParallelIterator};
+
+use crate::answers::Answer;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+// Each row and column of a pattern as a bitmask of its rocks
+#[derive(Debug)]
+pub struct Pattern {
+    rows: Vec<u32>,
+    cols: Vec<u32>,
+}
+
+impl TryFrom<&str> for Pattern {
+    type Error = color_eyre::Report;
+
+    fn try_from(input: &str) -> Result<Self> {
+        let grid = Grid::try_parse_with(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if grid.dimensions.0 > 32 || grid.dimensions.1 > 32 {
+            return Err(eyre!("Patterns can be at most 32 by 32"));
+        }
+        Ok(Self {
+            rows: grid.rows().map(|row| to_bits(row.iter())).collect(),
+            cols: (0..grid.dimensions.0)
+                .map(|x| to_bits(grid.col(x)))
+                .collect(),
+        })
+    }
+}
+
+fn to_bits<'a>(rocks: impl Iterator<Item = &'a bool>) -> u32 {
+    rocks
+        .enumerate()
+        .fold(0, |bits, (i, &rock)| bits | (rock as u32) << i)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Vec<Pattern>> {
+        input
+            .split("\n\n")
+            .par_bridge()
+            .map(Pattern::try_from)
+            .collect()
+    }
+
+    fn part1(grids: &Vec<Pattern>) -> Result<Answer> {
+        Ok(summarize(grids, find_symmetry).into())
+    }
+
+    fn part2(grids: &Vec<Pattern>) -> Result<Answer> {
+        Ok(summarize(grids, find_one_off_symmetry).into())
+    }
+}
+
+fn summarize(grids: &[Pattern], find: fn(&[u32]) -> u32) -> u64 {
+    grids
+        .par_iter()
+        .map(|g| (find(&g.cols) + 100 * find(&g.rows)) as u64)
+        .sum()
+}
+
+fn find_symmetry(nums: &[u32]) -> u32 {
+    'outer: for i in 1..nums.len() {
+        // Check if there is a symmetry line before row i
+        for j in 0..=i {
+            if i + j > nums.len() {
+                return i as u32;
+            }
+            if nums[i + j - 1] != nums[i - j] {
+                continue 'outer;
+            }
+        }
+        return i as u32;
+    }
+    0
+}
+
+fn find_one_off_symmetry(nums: &[u32]) -> u32 {
+    'outer: for i in 1..nums.len() {
+        let mut bit_diff = 0;
+        for j in 1..=i {
+            if i + j > nums.len() {
+                if bit_diff == 1 {
+                    return i as u32;
+                } else {
+                    continue 'outer;
+                }
+            }
+            bit_diff += (nums[i + j - 1] ^ nums[i - j]).count_ones();
+            if bit_diff > 1 {
+                continue 'outer;
+            }
+        }
+        if bit_diff == 1 {
+            return i as u32;
+        }
+    }
+    0
+}
@@ -0,0 +1,222 @@
+use std::{
+    cell::RefCell,
+    cmp::max,
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    rc::Rc,
+};
+
+use color_eyre::{eyre::eyre, Result};
+use pathfinding::directed::cycle_detection::brent;
+use rustc_hash::FxHasher;
+
+use crate::answers::Answer;
+use crate::grid::{Grid, Transform};
+use crate::solution::Solution;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Tilt {
+    East,
+    West,
+    North,
+    South,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Platform {
+    tilt: Option<Tilt>,
+    side_len: usize,
+    grid: Grid<char>,
+}
+
+impl Clone for Platform {
+    // I don't know why, but handrolling this is slightly (but statsig) faster than deriving Clone for Platform.
+    fn clone(&self) -> Self {
+        Self {
+            tilt: self.tilt,
+            side_len: self.side_len,
+            grid: self.grid.clone(),
+        }
+    }
+}
+
+impl Debug for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Platform {
+    #[inline]
+    fn grid_at(&self, row: usize, col: usize) -> &char {
+        self.grid.at(col, row)
+    }
+
+    fn rotate_tilt(&mut self) {
+        match self.tilt {
+            None | Some(Tilt::East) => self.tilt(Tilt::North),
+            Some(Tilt::North) => self.tilt(Tilt::West),
+            Some(Tilt::West) => self.tilt(Tilt::South),
+            Some(Tilt::South) => self.tilt(Tilt::East),
+        }
+    }
+
+    fn cycle(&mut self) {
+        self.rotate_tilt();
+        self.rotate_tilt();
+        self.rotate_tilt();
+        self.rotate_tilt();
+    }
+
+    fn north_load(&self) -> u64 {
+        let mut sum = 0;
+        for i in 0..self.side_len {
+            for j in 0..self.side_len {
+                if *self.grid_at(i, j) == 'O' {
+                    sum += (self.side_len - i) as u64;
+                }
+            }
+        }
+        sum
+    }
+
+    fn tilt(&mut self, tilt: Tilt) {
+        self.tilt = Some(tilt);
+        // Rocks roll to the top of a view turned so the tilted side is at the top
+        let transform = match tilt {
+            Tilt::North => Transform::Identity,
+            Tilt::West => Transform::RotateClockwise,
+            Tilt::South => Transform::Rotate180,
+            Tilt::East => Transform::RotateCounterClockwise,
+        };
+        let side_len = self.side_len;
+        let mut view = self.grid.view_mut(transform);
+        for col in 0..side_len {
+            let mut place_row = 0;
+            for row in 0..side_len {
+                match view.at(col, row) {
+                    '#' => {
+                        place_row = row + 1;
+                    }
+                    'O' => {
+                        *view.at_mut(col, row) = '.';
+                        *view.at_mut(col, place_row) = 'O';
+                        place_row += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&str> for Platform {
+    type Error = color_eyre::Report;
+
+    fn try_from(input: &str) -> Result<Self> {
+        let grid = Grid::try_parse_with(input, |c| matches!(c, 'O' | '#' | '.').then_some(c))?;
+        let (side_len, dim_y) = grid.dimensions;
+        if side_len != dim_y {
+            return Err(eyre!(
+                "The platform isn't square ({} by {})",
+                side_len,
+                dim_y
+            ));
+        }
+        Ok(Platform {
+            tilt: None,
+            side_len,
+            grid,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct LazyPlatformHashIterator {
+    platform: Rc<RefCell<Platform>>,
+    hashes_and_loads: Rc<RefCell<Vec<(u64, u64)>>>,
+    idx: usize,
+}
+
+impl LazyPlatformHashIterator {
+    fn new(platform: Platform) -> Self {
+        let mut s = FxHasher::default();
+        platform.hash(&mut s);
+        let hash = s.finish();
+        let load = platform.north_load();
+        let hashes_and_loads = Rc::new(RefCell::new(vec![(hash, load)]));
+        let platform = Rc::new(RefCell::new(platform));
+        Self {
+            platform: platform.clone(),
+            hashes_and_loads,
+            idx: 0,
+        }
+    }
+
+    fn cycle(&mut self) {
+        let gap = self.hashes_and_loads.borrow().len() as i32 - self.idx as i32;
+        if gap < 1 {
+            self.calc_forward(1 - gap as usize);
+        }
+        self.idx += 1;
+    }
+}
+
+impl LazyPlatformHashIterator {
+    fn calc_forward(&self, n: usize) {
+        for _ in 0..n {
+            let mut platform = self.platform.borrow_mut();
+            platform.cycle();
+            let mut s = FxHasher::default();
+            platform.hash(&mut s);
+            let hash = s.finish();
+            let load = platform.north_load();
+            {
+                self.hashes_and_loads.borrow_mut().push((hash, load));
+            }
+        }
+    }
+}
+
+impl PartialEq for LazyPlatformHashIterator {
+    fn eq(&self, other: &Self) -> bool {
+        // Wind forward until both idxes are within the hashes_and_loads
+        if max(self.idx, other.idx) >= self.hashes_and_loads.borrow().len() {
+            let n = max(self.idx, other.idx) - self.hashes_and_loads.borrow().len() + 1;
+            self.calc_forward(n);
+        }
+
+        self.hashes_and_loads.borrow()[self.idx].0 == other.hashes_and_loads.borrow()[other.idx].0
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Platform;
+
+    fn parse(input: &str) -> Result<Platform> {
+        Platform::try_from(input)
+    }
+
+    fn part1(platform: &Platform) -> Result<Answer> {
+        let mut platform = platform.clone();
+        platform.rotate_tilt();
+        Ok(platform.north_load().into())
+    }
+
+    fn part2(platform: &Platform) -> Result<Answer> {
+        let mut first_platform = platform.clone();
+        first_platform.cycle();
+
+        let it = LazyPlatformHashIterator::new(first_platform);
+
+        let (cycle_size, _, i) = brent(it.clone(), |mut it| {
+            it.cycle();
+            it
+        });
+        let equivalent = (1_000_000_000 - i - 1) % cycle_size;
+        let p2 = it.hashes_and_loads.borrow()[i + equivalent].1;
+        Ok(p2.into())
+    }
+}
@@ -7,6 +7,9 @@ use nom::{
     IResult,
 };
 
+use crate::answers::Answer;
+use crate::solution::Solution;
+
 #[derive(Clone, Debug)]
 struct Lens {
     label: String,
@@ -48,15 +51,29 @@ impl LensBox {
     }
 }
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let p1: u64 = input.split(',').map(|s| hash(0, s.chars())).sum();
-    Ok((p1, part2(input)?))
+pub struct Day15;
+
+impl Solution for Day15 {
+    // The comma separated steps
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Vec<&str>> {
+        Ok(input.split(',').collect())
+    }
+
+    fn part1(steps: &Vec<&str>) -> Result<Answer> {
+        Ok(steps.iter().map(|s| hash(0, s.chars())).sum::<u64>().into())
+    }
+
+    fn part2(steps: &Vec<&str>) -> Result<Answer> {
+        Ok(part2(steps)?.into())
+    }
 }
 
-pub fn part2(input: &str) -> Result<u64> {
+pub fn part2(steps: &[&str]) -> Result<u64> {
     let mut boxes: Vec<LensBox> = vec![LensBox::new(); 256];
-    input
-        .split(',')
+    steps
+        .iter()
         .map(|s| parse_instruction(s).unwrap().1)
         .for_each(|inst| follow_instruction(&inst, &mut boxes));
 
@@ -95,7 +112,7 @@ struct Instruction<'a> {
     op: Op,
 }
 
-fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+fn parse_instruction(input: &str) -> IResult<&str, Instruction<'_>> {
     let (remaining, label) = alpha1(input)?;
     let (remaining, mut op) =
         alt((value(Op::Remove, tag("-")), value(Op::Place(0), tag("="))))(remaining)?;
@@ -118,15 +135,8 @@ fn hash(mut current: u64, input: impl Iterator<Item = char>) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use crate::runner::test::{input_test, sample_test};
-
     use super::*;
 
-    sample_test!(sample_part1, 15, Some(1320), None);
-    sample_test!(sample_part2, 15, None, Some(145));
-    input_test!(part1, 15, Some(516657), None);
-    input_test!(part2, 15, None, Some(210906));
-
     #[test]
     fn hashes_hash() {
         assert_eq!(52, hash(0, "HASH".chars()));
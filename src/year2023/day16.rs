@@ -0,0 +1,103 @@
+use color_eyre::Result;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::answers::Answer;
+use crate::grid::{Dir, Grid};
+use crate::solution::Solution;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>> {
+        Grid::try_parse(input, |c| c)
+    }
+
+    fn part1(grid: &Grid<char>) -> Result<Answer> {
+        Ok(count_energized_tiles(grid, (0, 0), Dir::East).into())
+    }
+
+    fn part2(grid: &Grid<char>) -> Result<Answer> {
+        Ok(part2(grid).into())
+    }
+}
+
+// The most tiles energized by a beam entering from any edge
+fn part2(input_grid: &Grid<char>) -> u32 {
+    let mut max_top: u32 = 0;
+    let mut max_bottom: u32 = 0;
+    let mut max_left: u32 = 0;
+    let mut max_right: u32 = 0;
+
+    rayon::scope(|s| {
+        s.spawn(|_| {
+            max_top = (0..input_grid.dimensions.0)
+                .into_par_iter()
+                .map(|x| count_energized_tiles(input_grid, (x, 0), Dir::South))
+                .max()
+                .unwrap();
+        });
+        s.spawn(|_| {
+            max_bottom = (0..input_grid.dimensions.0)
+                .into_par_iter()
+                .map(|x| {
+                    count_energized_tiles(input_grid, (x, input_grid.dimensions.1 - 1), Dir::North)
+                })
+                .max()
+                .unwrap();
+        });
+        s.spawn(|_| {
+            max_left = (0..input_grid.dimensions.1)
+                .into_par_iter()
+                .map(|y| count_energized_tiles(input_grid, (0, y), Dir::East))
+                .max()
+                .unwrap();
+        });
+        s.spawn(|_| {
+            max_right = (0..input_grid.dimensions.1)
+                .into_par_iter()
+                .map(|y| {
+                    count_energized_tiles(input_grid, (input_grid.dimensions.0 - 1, y), Dir::West)
+                })
+                .max()
+                .unwrap();
+        });
+    });
+    *[max_top, max_bottom, max_left, max_right]
+        .iter()
+        .max()
+        .unwrap()
+}
+
+fn count_energized_tiles(grid: &Grid<char>, start_location: (usize, usize), start_dir: Dir) -> u32 {
+    let (dim_x, dim_y) = grid.dimensions;
+    let mut visited: Grid<u8> = Grid::new(0, dim_x, dim_y);
+    let mut to_visit: Vec<((usize, usize), Dir)> = vec![(start_location, start_dir)];
+    while let Some(((x, y), dir)) = to_visit.pop() {
+        let visited_point = visited.at_mut(x, y);
+
+        if *visited_point & (1 << dir as u8) > 0 {
+            continue;
+        }
+        *visited_point |= 1 << dir as u8;
+
+        let next = match (grid.at(x, y), dir) {
+            ('|', Dir::East | Dir::West) => [Some(Dir::North), Some(Dir::South)],
+            ('-', Dir::North | Dir::South) => [Some(Dir::West), Some(Dir::East)],
+            ('/', Dir::North | Dir::South) | ('\\', Dir::East | Dir::West) => {
+                [Some(dir.turn_right()), None]
+            }
+            ('/', Dir::East | Dir::West) | ('\\', Dir::North | Dir::South) => {
+                [Some(dir.turn_left()), None]
+            }
+            _ => [Some(dir), None],
+        };
+        for dir in next.into_iter().flatten() {
+            if let Some(pos) = grid.step(x, y, dir) {
+                to_visit.push((pos, dir));
+            }
+        }
+    }
+    visited.iter().filter(|&&bitset| bitset > 0).count() as u32
+}
@@ -0,0 +1,115 @@
+use color_eyre::Result;
+use pathfinding::directed::astar::astar;
+
+use crate::answers::Answer;
+use crate::grid::{Dir, Grid, Point};
+use crate::solution::Solution;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    fn dirs(self) -> [Dir; 2] {
+        match self {
+            Axis::Horizontal => [Dir::East, Dir::West],
+            Axis::Vertical => [Dir::North, Dir::South],
+        }
+    }
+
+    fn turn(self) -> Self {
+        match self {
+            Axis::Horizontal => Axis::Vertical,
+            Axis::Vertical => Axis::Horizontal,
+        }
+    }
+}
+
+// The axis to move along next, which is None at the start, where any way is allowed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Pos {
+    point: Point,
+    axis: Option<Axis>,
+}
+
+fn successors(pos: &Pos, grid: &Grid<u8>, min_move: usize, max_move: usize) -> Vec<(Pos, u64)> {
+    let mut v = vec![];
+    let axes = match pos.axis {
+        Some(axis) => vec![axis],
+        None => vec![Axis::Horizontal, Axis::Vertical],
+    };
+    for axis in axes {
+        for dir in axis.dirs() {
+            // Each block moved through costs its heat loss
+            let mut point = pos.point;
+            let mut cost = 0;
+            for n in 1..=max_move {
+                point = point.step(dir);
+                let Some((x, y)) = point.in_bounds(grid.dimensions) else {
+                    break;
+                };
+                cost += *grid.at(x, y) as u64;
+                if n >= min_move {
+                    let next = Pos {
+                        point,
+                        axis: Some(axis.turn()),
+                    };
+                    v.push((next, cost));
+                }
+            }
+        }
+    }
+    v
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>> {
+        Grid::try_parse_with(input, |c| c.to_digit(10).map(|d| d as u8))
+    }
+
+    fn part1(grid: &Grid<u8>) -> Result<Answer> {
+        Ok(solve(grid, 1, 3).into())
+    }
+
+    fn part2(grid: &Grid<u8>) -> Result<Answer> {
+        Ok(solve(grid, 4, 10).into())
+    }
+
+    fn run(input: &str) -> Result<(Answer, Answer)> {
+        let grid = Self::parse(input)?;
+        let (p1, p2) = rayon::join(|| solve(&grid, 1, 3), || solve(&grid, 4, 10));
+        Ok((p1.into(), p2.into()))
+    }
+}
+
+fn solve(grid: &Grid<u8>, min_move: usize, max_move: usize) -> u64 {
+    let (dim_x, dim_y) = grid.dimensions;
+    let start_pos = Pos {
+        point: Point::new(0, 0),
+        axis: None,
+    };
+    let end = Point::from((dim_x - 1, dim_y - 1));
+    let h = |pos: &Pos| astar_heuristic(pos, end);
+    let success = |pos: &Pos| pos.point == end;
+    let result = astar(
+        &start_pos,
+        |p| successors(p, grid, min_move, max_move),
+        h,
+        success,
+    );
+    if let Some((_, cost)) = result {
+        return cost;
+    }
+    0
+}
+
+fn astar_heuristic(pos: &Pos, end: Point) -> u64 {
+    // Just assume that the most direct path only has "1"s
+    (end.x - pos.point.x + end.y - pos.point.y) as u64
+}
@@ -7,27 +7,42 @@ use nom::{
     IResult,
 };
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let lines: Vec<Line> = input
-        .lines()
-        .map(parse_line)
-        .map(|r| r.map(|(_, l)| l))
-        .collect::<Result<Vec<_>, _>>()
-        .map_err(|e| anyhow!("Parse error: {}", e))?;
-
-    let processed_lines: Vec<Line> = lines
-        .iter()
-        .map(|l| {
-            let (dir, len) = color_to_instruction(l.color);
-            Line {
-                dir,
-                len,
-                color: l.color,
-            }
-        })
-        .collect();
-
-    Ok((calc_area(&lines)?, calc_area(&processed_lines)?))
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        input
+            .lines()
+            .map(parse_line)
+            .map(|r| r.map(|(_, l)| l))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("Parse error: {}", e))
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<Answer> {
+        Ok(calc_area(lines)?.into())
+    }
+
+    // The real instructions are hidden in the colors
+    fn part2(lines: &Vec<Line>) -> Result<Answer> {
+        let processed_lines: Vec<Line> = lines
+            .iter()
+            .map(|l| {
+                let (dir, len) = color_to_instruction(l.color);
+                Line {
+                    dir,
+                    len,
+                    color: l.color,
+                }
+            })
+            .collect();
+        Ok(calc_area(&processed_lines)?.into())
+    }
 }
 
 fn calc_area(lines: &[Line]) -> Result<u64> {
@@ -88,7 +103,7 @@ enum Dir {
 }
 
 #[derive(Debug)]
-struct Line {
+pub struct Line {
     dir: Dir,
     len: u32,
     color: u32, // argb
@@ -134,12 +149,6 @@ fn color_to_instruction(color: u32) -> (Dir, u32) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 18, Some(62), None);
-    sample_test!(sample_part2, 18, None, Some(952408144115));
-    input_test!(part1, 18, Some(31171), None);
-    input_test!(part2, 18, None, Some(131431655002266));
 
     #[test]
     fn can_parse_line() {
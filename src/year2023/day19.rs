@@ -11,7 +11,41 @@ use nom::{
     IResult,
 };
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+pub struct System {
+    workflows: Vec<Vec<Instruction>>,
+    parts: Vec<[u16; 4]>,
+    first: u16,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = System;
+
+    fn parse(input: &str) -> Result<System> {
+        parse(input)
+    }
+
+    fn part1(system: &System) -> Result<Answer> {
+        let mut sum = 0;
+        for part in &system.parts {
+            if follow_workflow(part, &system.workflows, system.first) {
+                sum += part.iter().map(|n| *n as u64).sum::<u64>();
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(system: &System) -> Result<Answer> {
+        let part_ranges: [Range<u16>; 4] = [1..4001, 1..4001, 1..4001, 1..4001];
+        Ok(count_accepted_combinantions(part_ranges, &system.workflows, system.first).into())
+    }
+}
+
+fn parse(input: &str) -> Result<System> {
     let mut symbols = new_symbol_table();
     let mut parts: Vec<[u16; 4]> = vec![];
     let mut seen_empty_line = false;
@@ -37,16 +71,11 @@ pub fn run(input: &str) -> Result<(u64, u64)> {
     for (id, ins) in parsed_instructions {
         workflows[id as usize] = ins;
     }
-    let first = symbols["in"];
-    let mut sum = 0;
-    for part in parts {
-        if follow_workflow(&part, &workflows, first) {
-            sum += part.iter().map(|n| *n as u64).sum::<u64>();
-        }
-    }
-    let part_ranges: [Range<u16>; 4] = [1..4001, 1..4001, 1..4001, 1..4001];
-    let p2 = count_accepted_combinantions(part_ranges, &workflows, first);
-    Ok((sum, p2))
+    Ok(System {
+        workflows,
+        parts,
+        first: symbols["in"],
+    })
 }
 
 fn follow_workflow(part: &[u16; 4], workflows: &[Vec<Instruction>], from: u16) -> bool {
@@ -228,7 +257,7 @@ enum ParsedInstruction<'a> {
     },
 }
 
-fn parse_instruction(input: &str) -> IResult<&str, ParsedInstruction> {
+fn parse_instruction(input: &str) -> IResult<&str, ParsedInstruction<'_>> {
     if let Ok((remaining, symbol)) = alpha1::<_, Error<_>>(input) {
         if remaining.starts_with(',') || remaining.starts_with('}') {
             return Ok((remaining, ParsedInstruction::Symbol(symbol)));
@@ -283,14 +312,9 @@ fn new_symbol_table() -> HashMap<String, u16> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
+    use crate::solution::Solution;
     use color_eyre::Result;
 
-    sample_test!(sample_part1, 19, Some(19114), None);
-    sample_test!(sample_part2, 19, None, Some(167409079868000));
-    input_test!(part1, 19, Some(374873), None);
-    input_test!(part2, 19, None, Some(122112157518711));
-
     #[test]
     fn can_parse_instruction() -> Result<()> {
         let s = "grc{a>3883:gb,a>3753:brm,a<3710:vhv,xr}";
@@ -308,33 +332,33 @@ mod tests {
     #[test]
     fn counts_all_combinations() -> Result<()> {
         let s = "in{A}";
-        let (_, p2) = super::run(s)?;
-        assert_eq!(4000 * 4000 * 4000 * 4000, p2);
+        let (_, p2) = Day19::run(s)?;
+        assert_eq!(Answer::Int(4000 * 4000 * 4000 * 4000), p2);
         Ok(())
     }
 
     #[test]
     fn counts_all_rejects() -> Result<()> {
         let s = "in{R}";
-        let (_, p2) = super::run(s)?;
-        assert_eq!(0, p2);
+        let (_, p2) = Day19::run(s)?;
+        assert_eq!(Answer::Int(0), p2);
         Ok(())
     }
 
     #[test]
     fn counts_half_combinations() -> Result<()> {
         let s = "in{x>2000:A,R}";
-        let (_, p2) = super::run(s)?;
-        assert_eq!(2000 * 4000 * 4000 * 4000, p2);
+        let (_, p2) = Day19::run(s)?;
+        assert_eq!(Answer::Int(2000 * 4000 * 4000 * 4000), p2);
         let s = "in{x<2001:A,R}";
-        let (_, p2) = super::run(s)?;
-        assert_eq!(2000 * 4000 * 4000 * 4000, p2);
+        let (_, p2) = Day19::run(s)?;
+        assert_eq!(Answer::Int(2000 * 4000 * 4000 * 4000), p2);
         let s = "in{x>2000:R,A}";
-        let (_, p2) = super::run(s)?;
-        assert_eq!(2000 * 4000 * 4000 * 4000, p2);
+        let (_, p2) = Day19::run(s)?;
+        assert_eq!(Answer::Int(2000 * 4000 * 4000 * 4000), p2);
         let s = "in{x<2001:R,A}";
-        let (_, p2) = super::run(s)?;
-        assert_eq!(2000 * 4000 * 4000 * 4000, p2);
+        let (_, p2) = Day19::run(s)?;
+        assert_eq!(Answer::Int(2000 * 4000 * 4000 * 4000), p2);
         Ok(())
     }
 }
@@ -1,6 +1,9 @@
 use rayon::prelude::*;
 use std::cmp::max;
 
+use crate::answers::Answer;
+use crate::solution::Solution;
+
 #[derive(Clone, Debug)]
 pub struct Set {
     r: u32,
@@ -50,29 +53,48 @@ mod parse {
     }
 }
 
-pub fn run(input: &str) -> color_eyre::Result<(u64, u64)> {
-    Ok((part1(input)? as u64, part2(input)? as u64))
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<(u32, Vec<Set>)>;
+
+    fn parse(input: &str) -> color_eyre::Result<Self::Parsed<'_>> {
+        input
+            .par_lines()
+            .map(|l| {
+                let (_remaining, game) = parse::parse_game(l)
+                    .map_err(|e| color_eyre::eyre::anyhow!("Parse error: {}", e))?;
+                Ok(game)
+            })
+            .collect()
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> color_eyre::Result<Answer> {
+        Ok(part1(games)?.into())
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> color_eyre::Result<Answer> {
+        Ok(part2(games)?.into())
+    }
 }
 
-fn part1(input: &str) -> color_eyre::Result<u32> {
-    let sum: u32 = input
-        .par_lines()
-        .map(|l| {
-            let (_remaining, (n, sets)) = parse::parse_game(l).unwrap();
+fn part1(games: &[(u32, Vec<Set>)]) -> color_eyre::Result<u32> {
+    let sum: u32 = games
+        .par_iter()
+        .map(|(n, sets)| {
             if sets.iter().any(|s| s.r > 12 || s.g > 13 || s.b > 14) {
                 return 0;
             }
-            n
+            *n
         })
         .sum();
     Ok(sum)
 }
 
-fn part2(input: &str) -> color_eyre::Result<u32> {
-    let sum: u32 = input
-        .par_lines()
-        .map(|l| {
-            let (_remaining, (_n, sets)) = parse::parse_game(l).unwrap();
+fn part2(games: &[(u32, Vec<Set>)]) -> color_eyre::Result<u32> {
+    let sum: u32 = games
+        .par_iter()
+        .map(|(_n, sets)| {
             let max = sets.iter().fold(Set { r: 0, g: 0, b: 0 }, |acc, set| Set {
                 r: max(acc.r, set.r),
                 g: max(acc.g, set.g),
@@ -86,15 +108,8 @@ fn part2(input: &str) -> color_eyre::Result<u32> {
 
 #[cfg(test)]
 mod tests {
-
-    use crate::runner::test::{input_test, sample_test};
-
     use super::*;
 
-    sample_test!(sample_part1, 2, Some(8), None);
-    input_test!(part1, 2, Some(2685), None);
-    sample_test!(sample_part2, 2, None, Some(2286));
-
     #[test]
     fn can_parse_color_set() -> color_eyre::Result<()> {
         let input = "4 blue, 16 green, 2 red";
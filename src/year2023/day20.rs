@@ -7,6 +7,8 @@ use nom::{
 };
 use num::Integer;
 
+use crate::answers::Answer;
+use crate::solution::Solution;
 use crate::symbol_table::SymbolTable;
 
 #[derive(Debug)]
@@ -16,24 +18,46 @@ struct Pulse {
     pulse_type: PulseType,
 }
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let (mut modules, _module_ids, broadcaster_id, rx_id) = build_modules(input)?;
+pub struct Network {
+    modules: Vec<Module>,
+    broadcaster_id: ModuleId,
+    rx_id: ModuleId,
+}
 
-    let (mut low_counts, mut high_counts) = (0, 0);
-    for _ in 0..1000 {
-        let (lc, hc) = push_button(&mut modules, broadcaster_id);
-        low_counts += lc;
-        high_counts += hc;
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed<'a> = Network;
+
+    fn parse(input: &str) -> Result<Network> {
+        let (modules, _module_ids, broadcaster_id, rx_id) = build_modules(input)?;
+        Ok(Network {
+            modules,
+            broadcaster_id,
+            rx_id,
+        })
     }
-    let p1 = low_counts * high_counts;
 
-    let p2 = if rx_id > 0 {
-        part2(&modules, broadcaster_id)
-    } else {
-        0
-    };
+    fn part1(network: &Network) -> Result<Answer> {
+        let mut modules = network.modules.clone();
+        let (mut low_counts, mut high_counts) = (0, 0);
+        for _ in 0..1000 {
+            let (lc, hc) = push_button(&mut modules, network.broadcaster_id);
+            low_counts += lc;
+            high_counts += hc;
+        }
+        Ok((low_counts * high_counts).into())
+    }
 
-    Ok((p1, p2))
+    // Only depends on how the modules are wired, not their state. Samples have no rx
+    // module, so nothing to answer.
+    fn part2(network: &Network) -> Result<Answer> {
+        if network.rx_id > 0 {
+            Ok(part2(&network.modules, network.broadcaster_id).into())
+        } else {
+            Ok(Answer::None)
+        }
+    }
 }
 
 fn push_button(modules: &mut [Module], broadcaster_id: ModuleId) -> (u64, u64) {
@@ -261,7 +285,7 @@ struct ParsedLine<'a> {
 // Example lines:
 // %rf -> lq, tj
 // broadcaster -> lp, fn, tp, zz
-fn parse_line(line: &str) -> IResult<&str, ParsedLine> {
+fn parse_line(line: &str) -> IResult<&str, ParsedLine<'_>> {
     let (remaining, (module_type, name)) = alt((
         value((ModuleType::BroadCaster, "broadcaster"), tag("broadcaster")),
         tuple((value(ModuleType::FlipFlop, tag("%")), alpha1)),
@@ -279,15 +303,3 @@ fn parse_line(line: &str) -> IResult<&str, ParsedLine> {
         },
     ))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::runner::test::{file_test, input_test, sample_test};
-
-    sample_test!(sample_part1, 20, Some(32000000), None);
-    input_test!(part1, 20, Some(743090292), None);
-    input_test!(part2, 20, None, Some(241528184647003));
-
-    file_test!(extended_part1, 20, "sample_2.txt", Some(11687500), None);
-}
@@ -0,0 +1,180 @@
+use color_eyre::Result;
+
+use crate::answers::Answer;
+use crate::grid::Grid;
+use crate::solution::Solution;
+
+// Distances from the start are all either part needs
+pub struct Garden {
+    grid: Grid<u8>,
+    min_distance: Grid<Option<u64>>,
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Garden> {
+        let grid = Grid::try_parse(input, |c| if c == 'S' { b'O' } else { c as u8 })?;
+        let min_distance = distances_from_center(&grid);
+        Ok(Garden { grid, min_distance })
+    }
+
+    fn part1(garden: &Garden) -> Result<Answer> {
+        Ok(garden
+            .min_distance
+            .iter()
+            .flatten()
+            .filter(|&&d| d <= 64 && d % 2 == 0)
+            .count()
+            .into())
+    }
+
+    fn part2(garden: &Garden) -> Result<Answer> {
+        Ok(part2(garden).into())
+    }
+}
+
+fn distances_from_center(grid: &Grid<u8>) -> Grid<Option<u64>> {
+    let center = (grid.dimensions.0 / 2, grid.dimensions.1 / 2);
+    grid.bfs([center], |&c| c != b'#').distances
+}
+
+fn part2(garden: &Garden) -> u64 {
+    let Garden { grid, min_distance } = garden;
+    let steps = 26501365;
+    // Assume the grid is square
+    // println!("Steps: {}", steps);
+    // println!("Extra: {}", extra);
+
+    // Looking at the input, we know the result is going to be a large diamond.
+    // Most of the inside of the diamond will be "complete" grids, with alternating
+    // parity. The outside of the diamond will be composed of grids partially filled
+    // from the corners.
+
+    // We also know that the resulting diamond will have an edge from corner-to-corner
+    // i.e. the same as part 1's edge PLUS ONE STEP (ffs).
+
+    let extra = steps % grid.dimensions.0;
+    assert_eq!(65, extra); // for p2
+    let width_tiles = ((steps - extra) / grid.dimensions.0) as u64;
+    // println!("Extra: {} spaces", extra);
+    // println!("Width: {} tiles", width_tiles);
+
+    let full_tile_odd = min_distance
+        .iter()
+        .flatten()
+        .filter(|&&d| d % 2 == 1)
+        .count() as u64;
+    let full_tile_even = min_distance
+        .iter()
+        .flatten()
+        .filter(|&&d| d % 2 == 0)
+        .count() as u64;
+    // println!("Full tile odd: {}", full_tile_odd);
+    // println!("Full tile even: {}", full_tile_even);
+
+    let four_corners_odd = min_distance
+        .iter()
+        .flatten()
+        .filter(|&&d| d % 2 == 1 && d > extra as u64)
+        .count() as u64;
+    // println!("extra {}", extra);
+    let four_corners_even = min_distance
+        .iter()
+        .flatten()
+        .filter(|&&d| d % 2 == 0 && d > extra as u64)
+        .count() as u64;
+    // println!("Four corners odd: {}", four_corners_odd);
+    // println!("Four corners even: {}", four_corners_even);
+
+    let mut p2: u64 = ((width_tiles + 1) * (width_tiles + 1)) * full_tile_odd
+        + (width_tiles * width_tiles) * full_tile_even
+        - ((width_tiles + 1) * four_corners_odd)
+        + width_tiles * four_corners_even;
+
+    // Print the points reachable in 65 steps
+    let mut print_grid = Grid::new(b'.', grid.dimensions.0, grid.dimensions.1);
+    for i in 0..grid.dimensions.0 {
+        for j in 0..grid.dimensions.1 {
+            if min_distance.at(i, j).is_some_and(|d| d <= 65) {
+                *print_grid.at_mut(i, j) = b'O';
+            }
+            if *grid.at(i, j) == b'#' {
+                *print_grid.at_mut(i, j) = b'#';
+            }
+        }
+    }
+    // println!("{}", print_grid);
+
+    // We have one spot in the diamond that is unreachable in 65 steps from center but reachable within 64 steps from corners
+    // so sub width from result
+    p2 -= width_tiles;
+
+    p2
+}
+
+#[allow(unused)]
+fn step(from: &Grid<u8>) -> Grid<u8> {
+    let mut to = from.clone();
+
+    for y in 0..from.dimensions.1 {
+        for x in 0..from.dimensions.0 {
+            if *to.at(x, y) == b'#' {
+                continue;
+            }
+            let reached = from
+                .neighbors4(x, y)
+                .any(|(_, (nx, ny))| *from.at(nx, ny) == b'O');
+            *to.at_mut(x, y) = if reached { b'O' } else { b'.' };
+        }
+    }
+
+    to
+}
+
+#[cfg(test)]
+mod tests {
+    use rustc_hash::FxHashSet;
+
+    use crate::grid::{Dir, Grid, Point};
+
+    const SAMPLE: &str = "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........
+";
+
+    // Plots reachable in exactly this many steps on the infinitely repeated map, the
+    // slow way
+    fn reachable_on_tiles(grid: &Grid<u8>, start: Point, steps: usize) -> usize {
+        let mut reached = FxHashSet::from_iter([start]);
+        for _ in 0..steps {
+            reached = reached
+                .iter()
+                .flat_map(|p| Dir::ALL.map(|dir| p.step(dir)))
+                .filter(|p| *grid.at_tiled(p.x, p.y) != b'#')
+                .collect();
+        }
+        reached.len()
+    }
+
+    #[test]
+    fn walks_the_tiled_sample() {
+        let grid = Grid::parse(SAMPLE, |c| c as u8);
+        let start = Point::from(grid.find(|&c| c == b'S').unwrap());
+        assert_eq!(16, reachable_on_tiles(&grid, start, 6));
+        assert_eq!(50, reachable_on_tiles(&grid, start, 10));
+        assert_eq!(1594, reachable_on_tiles(&grid, start, 50));
+        assert_eq!(6536, reachable_on_tiles(&grid, start, 100));
+    }
+}
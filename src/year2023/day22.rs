@@ -5,9 +5,65 @@ use nom::{bytes::complete::tag, IResult};
 
 use nom::character::complete::u16 as nu16;
 
+use crate::answers::Answer;
 use crate::grid::Grid;
+use crate::solution::Solution;
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
+// For each settled tet, the tets directly below that support it
+pub struct Stack {
+    supporters: Vec<Vec<usize>>,
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Parsed<'a> = Stack;
+
+    fn parse(input: &str) -> Result<Stack> {
+        settle(input)
+    }
+
+    fn part1(stack: &Stack) -> Result<Answer> {
+        let supporters = &stack.supporters;
+        let mut removable = 0;
+
+        'outer: for i in 0..supporters.len() {
+            for s in supporters {
+                if s.len() == 1 && s[0] == i {
+                    continue 'outer;
+                }
+            }
+
+            removable += 1;
+        }
+        Ok(removable.into())
+    }
+
+    fn part2(stack: &Stack) -> Result<Answer> {
+        let supporters = &stack.supporters;
+        let mut p2_sum = 0;
+        // For each tet we find the number of other bricks it transitively supports
+        for i in 0..supporters.len() {
+            let mut to_remove: Vec<bool> = vec![false; supporters.len()];
+            to_remove[i] = true;
+
+            for (j, s_j) in supporters.iter().enumerate() {
+                if s_j.len() == 1 && s_j[0] == i {
+                    to_remove[j] = true;
+                    continue;
+                }
+                if !s_j.is_empty() && s_j.iter().all(|x| to_remove[*x]) {
+                    to_remove[j] = true;
+                    continue;
+                }
+            }
+            p2_sum += to_remove.iter().filter(|b| **b).count() as u64 - 1;
+        }
+        Ok(p2_sum.into())
+    }
+}
+
+fn settle(input: &str) -> Result<Stack> {
     let (mut dim_x, mut dim_y, mut dim_z) = (0, 0, 0);
     let mut tets: Vec<_> = input
         .lines()
@@ -19,7 +75,7 @@ pub fn run(input: &str) -> Result<(u64, u64)> {
             Ok(tet)
         })
         .collect::<Result<Vec<_>>>()?;
-    tets.sort_by(|a, b| a.start.2.cmp(&b.start.2));
+    tets.sort_by_key(|t| t.start.2);
     let mut height_map = Grid::<u16>::new(0, dim_x as usize + 1, dim_y as usize + 1);
     for tet in &mut tets {
         // "Place" tet onto grid
@@ -91,39 +147,7 @@ pub fn run(input: &str) -> Result<(u64, u64)> {
         });
     });
 
-    let mut removable = 0;
-
-    'outer: for (i, _) in tets.iter().enumerate() {
-        for s in &supporters {
-            if s.len() == 1 && s[0] == i {
-                continue 'outer;
-            }
-        }
-
-        removable += 1;
-    }
-    let p1 = removable;
-
-    let mut p2_sum = 0;
-    // For each tet we find the number of other bricks it transitively supports
-    for (i, _) in tets.iter().enumerate() {
-        let mut to_remove: Vec<bool> = vec![false; tets.len()];
-        to_remove[i] = true;
-
-        for (j, s_j) in supporters.iter().enumerate() {
-            if s_j.len() == 1 && s_j[0] == i {
-                to_remove[j] = true;
-                continue;
-            }
-            if !s_j.is_empty() && s_j.iter().all(|x| to_remove[*x]) {
-                to_remove[j] = true;
-                continue;
-            }
-        }
-        p2_sum += to_remove.iter().filter(|b| **b).count() as u64 - 1;
-    }
-
-    Ok((p1, p2_sum))
+    Ok(Stack { supporters })
 }
 
 #[derive(Debug)]
@@ -196,14 +220,3 @@ fn parse_tet(line: &str) -> IResult<&str, Tetromino> {
 
     Ok((line, Tetromino::new(start, end)))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 22, Some(5), None);
-    sample_test!(sample_part2, 22, None, Some(7));
-    input_test!(part1, 22, Some(499), None);
-    input_test!(part2, 22, None, Some(95059));
-}
@@ -2,23 +2,34 @@ use std::collections::BTreeMap;
 
 use color_eyre::Result;
 
-use crate::grid::Grid;
+use crate::answers::Answer;
+use crate::grid::{Dir, Grid};
+use crate::solution::Solution;
 
 type Pos = (usize, usize);
 
 type NodeId = u8;
 type Distance = u16;
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let grid = Grid::<u8>::parse(input, |c| c as u8);
-    let p1 = solve(&grid, true);
-    let p2 = solve(&grid, false);
-    Ok((p1, p2))
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>> {
+        Grid::try_parse(input, |c| c as u8)
+    }
+
+    fn part1(grid: &Grid<u8>) -> Result<Answer> {
+        Ok(solve(grid, true).into())
+    }
+
+    fn part2(grid: &Grid<u8>) -> Result<Answer> {
+        Ok(solve(grid, false).into())
+    }
 }
 
 fn solve(grid: &Grid<u8>, consider_slopes: bool) -> u64 {
-    let (dim_x, dim_y) = grid.dimensions;
-
     // Convert to a graph
     // Find all the nodes
 
@@ -37,38 +48,18 @@ fn solve(grid: &Grid<u8>, consider_slopes: bool) -> u64 {
     // .  for every neighbour
     // .    find the next node and the distance
     for (i, n) in nodes.iter().enumerate() {
-        for (dx, dy) in &[(0, 1), (1, 0), (0, -1), (-1, 0)] {
-            let (nx, ny) = (n.0 as i32 + dx, n.1 as i32 + dy);
-            if nx < 0 || ny < 0 || nx >= dim_x as i32 || ny >= dim_y as i32 {
-                continue;
-            }
-            if *grid.at(nx as usize, ny as usize) == b'#' {
+        for (dir, (nx, ny)) in grid.neighbors4(n.0, n.1) {
+            if *grid.at(nx, ny) == b'#' {
                 continue;
             }
-            let slope_ok = if !consider_slopes {
-                true
-            } else {
-                match (dx, dy, grid.at(nx as usize, ny as usize)) {
-                    (0, 1, b'v') => true,
-                    (1, 0, b'>') => true,
-                    (0, -1, b'^') => true,
-                    (-1, 0, b'<') => true,
-                    (_, _, b'>') | (_, _, b'<') | (_, _, b'^') | (_, _, b'v') => false,
-                    _ => true,
-                }
-            };
-            if !slope_ok {
+            if consider_slopes && !slope_ok(dir, *grid.at(nx, ny)) {
                 continue;
             }
             // Walk the node and find next node and distance or dead end
             // NB we're guaranteed only to ever find one next node
-            if let Some((next_node, distance)) = walk_path(
-                grid,
-                &node_ids,
-                (nx as usize, ny as usize),
-                (n.0, n.1),
-                consider_slopes,
-            ) {
+            if let Some((next_node, distance)) =
+                walk_path(grid, &node_ids, (nx, ny), *n, consider_slopes)
+            {
                 edges
                     .entry(i as NodeId)
                     .or_default()
@@ -159,47 +150,36 @@ fn walk_path(
     prev: Pos,
     consider_slopes: bool,
 ) -> Option<(NodeId, Distance)> {
-    let (dim_x, dim_y) = grid.dimensions;
-    for (dx, dy) in &[(0, 1), (1, 0), (0, -1), (-1, 0)] {
-        let (nx, ny) = (pos.0 as i32 + dx, pos.1 as i32 + dy);
-        if nx < 0 || ny < 0 || nx >= dim_x as i32 || ny >= dim_y as i32 {
-            continue;
-        }
-        if *grid.at(nx as usize, ny as usize) == b'#' {
+    for (dir, (nx, ny)) in grid.neighbors4(pos.0, pos.1) {
+        if *grid.at(nx, ny) == b'#' {
             continue;
         }
-        if (nx as usize, ny as usize) == prev {
+        if (nx, ny) == prev {
             continue;
         }
-        if node_ids.contains_key(&(nx as usize, ny as usize)) {
-            return Some((*node_ids.get(&(nx as usize, ny as usize)).unwrap(), 1));
+        if let Some(&node) = node_ids.get(&(nx, ny)) {
+            return Some((node, 1));
         }
-        if consider_slopes {
-            // return none if we've hit a slope facing the wrong way
-            let slope_ok = match (dx, dy, grid.at(nx as usize, ny as usize)) {
-                (0, 1, b'v') => true,
-                (1, 0, b'>') => true,
-                (0, -1, b'^') => true,
-                (-1, 0, b'<') => true,
-                (_, _, b'>') | (_, _, b'<') | (_, _, b'^') | (_, _, b'v') => false,
-                _ => true,
-            };
-            if !slope_ok {
-                return None;
-            }
+        // return none if we've hit a slope facing the wrong way
+        if consider_slopes && !slope_ok(dir, *grid.at(nx, ny)) {
+            return None;
         }
-        return walk_path(
-            grid,
-            node_ids,
-            (nx as usize, ny as usize),
-            pos,
-            consider_slopes,
-        )
-        .map(|(n, d)| (n, d + 1));
+        return walk_path(grid, node_ids, (nx, ny), pos, consider_slopes).map(|(n, d)| (n, d + 1));
     }
     None
 }
 
+// Slopes can only be walked down
+fn slope_ok(dir: Dir, tile: u8) -> bool {
+    match tile {
+        b'v' => dir == Dir::South,
+        b'>' => dir == Dir::East,
+        b'^' => dir == Dir::North,
+        b'<' => dir == Dir::West,
+        _ => true,
+    }
+}
+
 fn find_nodes(grid: &Grid<u8>) -> Vec<Pos> {
     let (dim_x, dim_y) = grid.dimensions;
     let mut nodes = vec![];
@@ -226,31 +206,13 @@ fn find_nodes(grid: &Grid<u8>) -> Vec<Pos> {
         if *c != b'.' {
             return;
         }
-        let mut neighbour_count = 0;
-        for (dx, dy) in &[(0, 1), (1, 0), (0, -1), (-1, 0)] {
-            let (nx, ny) = (x as i32 + dx, y as i32 + dy);
-            if nx < 0 || ny < 0 || nx >= dim_x as i32 || ny >= dim_y as i32 {
-                continue;
-            }
-            if *grid.at(nx as usize, ny as usize) == b'#' {
-                continue;
-            }
-            neighbour_count += 1;
-        }
+        let neighbour_count = grid
+            .neighbors4(x, y)
+            .filter(|&(_, (nx, ny))| *grid.at(nx, ny) != b'#')
+            .count();
         if neighbour_count > 2 {
             nodes.push((x, y));
         }
     });
     nodes
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 23, Some(94), None);
-    sample_test!(sample_part2, 23, None, Some(154));
-    input_test!(part1, 23, Some(2186), None);
-    input_test!(part2, 23, None, Some(6802));
-}
@@ -5,8 +5,11 @@ use nom::character::complete::{i128 as ni128, multispace0};
 use nom::sequence::tuple;
 use nom::IResult;
 
-#[derive(Debug, PartialEq)]
-struct Line {
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line {
     at: (f64, f64, f64),
     dir: (i16, i16, i16),
 }
@@ -125,18 +128,29 @@ fn cross_product(dir1: (f64, f64, f64), dir2: (f64, f64, f64)) -> (f64, f64, f64
     )
 }
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let p1 = part1(input, 200_000_000_000_000.0, 400_000_000_000_000.0)?;
-    // let p2 = part2(input)?;
-    let p2 = part2_faster(input)?;
-    Ok((p1, p2))
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed<'a> = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        input
+            .lines()
+            .map(|l| Ok(parse_line(l).map_err(|e| anyhow!("Parse error: {e}"))?.1))
+            .collect()
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<Answer> {
+        Ok(part1(lines, 200_000_000_000_000.0, 400_000_000_000_000.0)?.into())
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<Answer> {
+        Ok(part2_faster(lines)?.into())
+    }
 }
 
-fn part2_faster(input: &str) -> Result<u64> {
-    let mut lines: Vec<_> = input
-        .lines()
-        .map(|l| Ok(parse_line(l).map_err(|e| anyhow!("Parse error: {e}"))?.1))
-        .collect::<Result<Vec<_>>>()?;
+fn part2_faster(lines: &[Line]) -> Result<u64> {
+    let mut lines = lines.to_vec();
 
     lines.sort_by(|a, b| a.dir.0.partial_cmp(&b.dir.0).unwrap());
 
@@ -231,12 +245,7 @@ fn is_int(x: f64) -> bool {
     x.fract() == 0.0
 }
 
-fn part1(input: &str, min_xy: f64, max_xy: f64) -> Result<u64> {
-    let lines: Vec<_> = input
-        .lines()
-        .map(|l| Ok(parse_line(l).map_err(|e| anyhow!("Parse error: {e}"))?.1))
-        .collect::<Result<Vec<_>>>()?;
-
+fn part1(lines: &[Line], min_xy: f64, max_xy: f64) -> Result<u64> {
     let mut count = 0;
     for (i, l1) in lines.iter().enumerate() {
         for l2 in lines.iter().take(i) {
@@ -298,15 +307,11 @@ fn parse_line(input: &str) -> IResult<&str, Line> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part2, 24, None, Some(47));
-    input_test!(part1, 24, Some(19523), None);
 
     #[test]
     fn sample_part1() -> Result<()> {
-        let input = std::fs::read_to_string(format!("inputs/24/sample.txt"))?;
-        let p1 = super::part1(&input, 7.0, 27.0)?;
+        let input = crate::inputs::read(2023, 24, "sample.txt")?;
+        let p1 = super::part1(&Day24::parse(&input)?, 7.0, 27.0)?;
         assert_eq!(2, p1);
         Ok(())
     }
@@ -9,11 +9,42 @@ use nom::{
 use petgraph::graph::UnGraph;
 use rustworkx_core::connectivity::stoer_wagner_min_cut;
 
+use crate::answers::Answer;
+use crate::solution::Solution;
 use crate::symbol_table::SymbolTable;
 
 type NodeId = u16;
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed<'a> = Wiring;
+
+    fn parse(input: &str) -> Result<Wiring> {
+        parse(input)
+    }
+
+    fn part1(wiring: &Wiring) -> Result<Answer> {
+        let min_cut_res: Result<Option<(usize, Vec<_>)>> =
+            stoer_wagner_min_cut(&wiring.graph, |_| Ok(1));
+        let min_cut_res = min_cut_res?.unwrap();
+
+        let partition_1 = min_cut_res.1.len() as u64;
+        Ok((partition_1 * (wiring.node_count - partition_1)).into())
+    }
+
+    // There's no part 2 on the last day
+    fn part2(_wiring: &Wiring) -> Result<Answer> {
+        Ok(Answer::None)
+    }
+}
+
+pub struct Wiring {
+    graph: UnGraph<u16, ()>,
+    node_count: u64,
+}
+
+fn parse(input: &str) -> Result<Wiring> {
     let mut st = SymbolTable::new();
     let parsed_lines: Vec<(NodeId, Vec<NodeId>)> = input
         .lines()
@@ -39,12 +70,10 @@ pub fn run(input: &str) -> Result<(u64, u64)> {
         }
     }
 
-    let min_cut_res: Result<Option<(usize, Vec<_>)>> = stoer_wagner_min_cut(&graph, |_| Ok(1));
-    let min_cut_res = min_cut_res?.unwrap();
-
-    let partition_1 = min_cut_res.1.len() as u64;
-    let p1 = partition_1 * (st.len() as u64 - partition_1);
-    Ok((p1, 0))
+    Ok(Wiring {
+        graph,
+        node_count: st.len() as u64,
+    })
 }
 
 // Parse lines like "tsx: vrm vsc bjj dbq cth vbm gmb cjd"
@@ -55,12 +84,3 @@ fn parse_line<'a>(line: &'a str, st: &mut SymbolTable) -> IResult<&'a str, (Node
     let conns = conns.iter().map(|n| st.get(n)).collect::<Vec<NodeId>>();
     Ok((remaining, (nid, conns)))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 25, Some(54), None);
-    input_test!(part1, 25, Some(506202), None);
-}
@@ -1,6 +1,10 @@
 use std::cmp::min;
 
-use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::answers::Answer;
+use crate::grid::Grid;
+use crate::solution::Solution;
 
 #[derive(Clone)]
 struct Part {
@@ -10,21 +14,40 @@ struct Part {
     end: usize,
 }
 
-pub fn run(input: &str) -> color_eyre::Result<(u64, u64)> {
-    let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
-    let parts = find_parts(&grid);
-    Ok((part1(&parts)? as u64, part2(&grid, &parts)? as u64))
+pub struct Schematic {
+    grid: Grid<char>,
+    parts: Vec<Part>,
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Schematic;
+
+    fn parse(input: &str) -> color_eyre::Result<Schematic> {
+        let grid = Grid::try_parse(input, |c| c)?;
+        let parts = find_parts(&grid);
+        Ok(Schematic { grid, parts })
+    }
+
+    fn part1(schematic: &Schematic) -> color_eyre::Result<Answer> {
+        Ok(part1(&schematic.parts)?.into())
+    }
+
+    fn part2(schematic: &Schematic) -> color_eyre::Result<Answer> {
+        Ok(part2(&schematic.grid, &schematic.parts)?.into())
+    }
 }
 
 fn part1(parts: &[Part]) -> color_eyre::Result<u32> {
     Ok(parts.iter().map(|p| p.num).sum())
 }
 
-fn part2(grid: &[Vec<char>], parts: &[Part]) -> color_eyre::Result<u32> {
-    let sum = grid
-        .par_iter()
-        .enumerate()
-        .map(|(line_num, line)| {
+fn part2(grid: &Grid<char>, parts: &[Part]) -> color_eyre::Result<u32> {
+    let sum = (0..grid.dimensions.1)
+        .into_par_iter()
+        .map(|line_num| {
+            let line = grid.row(line_num);
             let mut line_sum = 0;
             for (i, c) in line.iter().enumerate() {
                 if *c == '*' {
@@ -48,9 +71,9 @@ fn part2(grid: &[Vec<char>], parts: &[Part]) -> color_eyre::Result<u32> {
     Ok(sum)
 }
 
-fn find_parts(grid: &[Vec<char>]) -> Vec<Part> {
+fn find_parts(grid: &Grid<char>) -> Vec<Part> {
     let mut nums: Vec<Part> = Vec::new();
-    for (line_num, l) in grid.iter().enumerate() {
+    for (line_num, l) in grid.rows().enumerate() {
         let mut n = 0;
         let mut in_num = false;
         let mut num_start = 0;
@@ -96,20 +119,21 @@ fn find_parts(grid: &[Vec<char>]) -> Vec<Part> {
 }
 
 pub fn has_adjacent_special_chars(
-    grid: &[Vec<char>],
+    grid: &Grid<char>,
     line: usize,
     start: usize,
     end: usize,
 ) -> bool {
-    if start > 0 && is_special(grid[line][start - 1])
-        || end < grid[line].len() - 1 && is_special(grid[line][end])
+    let (dim_x, dim_y) = grid.dimensions;
+    if start > 0 && is_special(*grid.at(start - 1, line))
+        || end < dim_x - 1 && is_special(*grid.at(end, line))
     {
         return true;
     }
     let st = if start > 0 { start - 1 } else { start };
-    let ed = min(end + 1, grid[0].len() - 1);
-    let check_line = |l: usize| grid[l][st..ed].iter().any(|c| is_special(*c));
-    line > 0 && check_line(line - 1) || line < grid.len() - 1 && check_line(line + 1)
+    let ed = min(end + 1, dim_x - 1);
+    let check_line = |l: usize| grid.row(l)[st..ed].iter().any(|c| is_special(*c));
+    line > 0 && check_line(line - 1) || line < dim_y - 1 && check_line(line + 1)
 }
 
 fn is_special(c: char) -> bool {
@@ -124,19 +148,12 @@ fn is_adjacent(line: usize, col: usize, part: &Part) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use crate::runner::test::{input_test, sample_test};
-
     use super::*;
 
-    sample_test!(sample_part1, 3, Some(4361), None);
-    input_test!(part1, 3, Some(527369), None);
-    input_test!(part2, 3, None, Some(73074886));
-    sample_test!(sample_part2, 3, None, Some(467835));
-
     #[test]
     fn diagonal_input() {
         let input = "*...\n.123";
-        let grid: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+        let grid = Grid::parse(input, |c| c);
         let parts = find_parts(&grid);
         super::part1(&parts).unwrap();
     }
@@ -11,13 +11,26 @@ use nom::{
 };
 use rayon::{iter::ParallelIterator, str::ParallelString};
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let matches = input
-        .par_lines()
-        .map(scratchcard_matches)
-        .collect::<Result<Vec<_>, _>>()?;
+use crate::answers::Answer;
+use crate::solution::Solution;
 
-    Ok((part1(&matches)? as u64, part2(&matches)? as u64))
+pub struct Day4;
+
+impl Solution for Day4 {
+    // Number of winning numbers on each card
+    type Parsed<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        input.par_lines().map(scratchcard_matches).collect()
+    }
+
+    fn part1(matches: &Vec<usize>) -> Result<Answer> {
+        Ok(part1(matches)?.into())
+    }
+
+    fn part2(matches: &Vec<usize>) -> Result<Answer> {
+        Ok(part2(matches)?.into())
+    }
 }
 
 fn part1(matches: &[usize]) -> Result<u32> {
@@ -61,12 +74,6 @@ fn scratchcard_matches(card: &str) -> Result<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 4, Some(13), None);
-    sample_test!(sample_part2, 4, None, Some(30));
-    input_test!(part1, 4, Some(17782), None);
-    input_test!(part2, 4, None, Some(8477787));
 
     #[test]
     fn test_parse_line() {
@@ -10,9 +10,31 @@ use nom::multi::{many0, separated_list0};
 use nom::sequence::tuple;
 use nom::{bytes::complete::tag, multi::separated_list1, sequence::preceded, IResult};
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let (_, (seeds, mappings)) = parse(input).map_err(|e| anyhow!("Parse error: {}", e))?;
-    Ok((part1(&seeds, &mappings)?, part2(&seeds, &mappings)?))
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    mappings: Vec<Mapping>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = Almanac;
+
+    fn parse(input: &str) -> Result<Almanac> {
+        let (_, (seeds, mappings)) = parse(input).map_err(|e| anyhow!("Parse error: {}", e))?;
+        Ok(Almanac { seeds, mappings })
+    }
+
+    fn part1(almanac: &Almanac) -> Result<Answer> {
+        Ok(part1(&almanac.seeds, &almanac.mappings)?.into())
+    }
+
+    fn part2(almanac: &Almanac) -> Result<Answer> {
+        Ok(part2(&almanac.seeds, &almanac.mappings)?.into())
+    }
 }
 
 fn part1(seeds: &[u64], mappings: &[Mapping]) -> Result<u64> {
@@ -143,16 +165,10 @@ mod tests {
     use color_eyre::eyre::anyhow;
 
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 5, Some(35), None);
-    sample_test!(sample_part2, 5, None, Some(46));
-    input_test!(part1, 5, Some(251346198), None);
-    input_test!(part2, 5, None, Some(72263011));
 
     #[test]
     fn can_parse_sample() -> Result<()> {
-        let input = std::fs::read_to_string("inputs/5/sample.txt")?;
+        let input = crate::inputs::read(2023, 5, "sample.txt")?;
         let (_remaining, (seeds, mappings)) = parse(&input).map_err(|_| anyhow!("parse error"))?;
         assert_eq!(4, seeds.len());
         assert_eq!(55, seeds[2]);
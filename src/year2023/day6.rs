@@ -9,24 +9,44 @@ use nom::{
     IResult,
 };
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    Ok((part1(input)? as u64, part2(input)?))
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+// The two parts read the sheet differently, so we keep both readings
+pub struct Races {
+    times: Vec<u32>,
+    distances: Vec<u32>,
+    single_race: (u64, u64),
 }
 
-fn part1(input: &str) -> Result<u64> {
-    let (_remaining, (times, distances)) =
-        parse_part1(input).map_err(|e| anyhow!("Parse error: {}", e))?;
-    let mut product = 1;
-    for (t, d) in times.iter().zip(distances.iter()) {
-        let n = solve(*t as u64, *d as u64);
-        product *= n;
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Races;
+
+    fn parse(input: &str) -> Result<Races> {
+        let (_remaining, (times, distances)) =
+            parse_part1(input).map_err(|e| anyhow!("Parse error: {}", e))?;
+        Ok(Races {
+            times,
+            distances,
+            single_race: parse_part2(input),
+        })
     }
-    Ok(product)
-}
 
-fn part2(input: &str) -> Result<u64> {
-    let (t, d) = parse_part2(input);
-    Ok(solve(t, d))
+    fn part1(races: &Races) -> Result<Answer> {
+        let mut product = 1;
+        for (t, d) in races.times.iter().zip(races.distances.iter()) {
+            let n = solve(*t as u64, *d as u64);
+            product *= n;
+        }
+        Ok(product.into())
+    }
+
+    fn part2(races: &Races) -> Result<Answer> {
+        let (t, d) = races.single_race;
+        Ok(solve(t, d).into())
+    }
 }
 
 fn parse_part1(input: &str) -> IResult<&str, (Vec<u32>, Vec<u32>)> {
@@ -73,16 +93,7 @@ fn solve(t: u64, d: u64) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-    use crate::{
-        day6::solve,
-        runner::test::{input_test, sample_test},
-    };
-
-    sample_test!(sample_part1, 6, Some(288), None);
-    sample_test!(sample_part2, 6, None, Some(71503));
-    input_test!(part1, 6, Some(512295), None);
-    input_test!(part2, 6, None, Some(36530883));
+    use super::solve;
 
     #[test]
     fn can_solve_problem() {
@@ -3,8 +3,11 @@ use std::cmp::Ordering;
 use color_eyre::Result;
 use rayon::{iter::ParallelIterator, str::ParallelString};
 
-#[derive(Debug, PartialEq, Eq)]
-struct Hand {
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
     cards: [u8; 5],
     typ: HandType,
     typ_with_wild_jokers: HandType,
@@ -25,7 +28,7 @@ impl Hand {
         }
 
         // Compare cards
-        for (a, b) in self.cards.into_iter().zip(other.cards.into_iter()) {
+        for (a, b) in self.cards.into_iter().zip(other.cards) {
             match (a, b, jokers_wild) {
                 (11, 11, _) => {}
                 (11, _, true) => return Ordering::Less,
@@ -41,7 +44,7 @@ impl Hand {
 
 type Bid = u32;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 enum HandType {
     HighCard,
     OnePair,
@@ -79,12 +82,23 @@ impl From<([u8; 5], bool)> for HandType {
     }
 }
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let mut v: Vec<(Hand, Bid)> = input
-        .par_lines()
-        .map(parse_hand)
-        .collect::<Result<Vec<_>>>()?;
-    Ok((solve(&mut v, false)?, solve(&mut v, true)?))
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Vec<(Hand, Bid)>;
+
+    fn parse(input: &str) -> Result<Vec<(Hand, Bid)>> {
+        input.par_lines().map(parse_hand).collect()
+    }
+
+    // Each part sorts its own copy of the hands
+    fn part1(hands: &Vec<(Hand, Bid)>) -> Result<Answer> {
+        Ok(solve(&mut hands.clone(), false)?.into())
+    }
+
+    fn part2(hands: &Vec<(Hand, Bid)>) -> Result<Answer> {
+        Ok(solve(&mut hands.clone(), true)?.into())
+    }
 }
 
 fn solve(v: &mut [(Hand, Bid)], jokers_wild: bool) -> Result<u64> {
@@ -119,14 +133,3 @@ fn parse_hand(line: &str) -> Result<(Hand, Bid)> {
     };
     Ok((hand, bid))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 7, Some(6440), None);
-    sample_test!(sample_part2, 7, None, Some(5905));
-    input_test!(part1, 7, Some(250347426), None);
-    input_test!(part2, 7, None, Some(251224870));
-}
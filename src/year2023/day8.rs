@@ -10,6 +10,9 @@ use num::Integer;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use std::collections::{BTreeSet, HashMap};
 
+use crate::answers::Answer;
+use crate::solution::Solution;
+
 #[derive(Debug, PartialEq, Eq)]
 enum Direction {
     L,
@@ -19,19 +22,33 @@ enum Direction {
 type Node = u16;
 type StepCount = u64;
 
-pub fn run(input: &str) -> Result<(u64, u64)> {
-    let parsed = parse(input)?;
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = ParseOutput<'a>;
+
+    fn parse(input: &str) -> Result<ParseOutput<'_>> {
+        parse(input)
+    }
 
-    let p1_start = parsed.names_map["AAA"];
-    let (p1, p2) = rayon::join(
-        || part1(&parsed.directions, p1_start, &parsed.nodes),
-        || part2(&parsed),
-    );
-    Ok((p1?, p2?))
+    fn part1(parsed: &ParseOutput) -> Result<Answer> {
+        Ok(part1(parsed)?.into())
+    }
+
+    fn part2(parsed: &ParseOutput) -> Result<Answer> {
+        Ok(part2(parsed)?.into())
+    }
+
+    fn run(input: &str) -> Result<(Answer, Answer)> {
+        let parsed = parse(input)?;
+        let (p1, p2) = rayon::join(|| part1(&parsed), || part2(&parsed));
+        Ok((p1?.into(), p2?.into()))
+    }
 }
 
-fn part1(directions: &[Direction], start: u16, nodes: &[NodeMapEntry]) -> Result<u64> {
-    find_first_ending(directions, start, nodes)
+fn part1(parsed: &ParseOutput) -> Result<u64> {
+    let start = parsed.names_map["AAA"];
+    find_first_ending(&parsed.directions, start, &parsed.nodes)
 }
 
 fn part2(parsed: &ParseOutput) -> Result<u64> {
@@ -74,14 +91,14 @@ struct NodeMapEntry {
     is_end: bool,
 }
 
-struct ParseOutput<'a> {
+pub struct ParseOutput<'a> {
     directions: Vec<Direction>,
     names_map: HashMap<&'a str, u16>,
     nodes: Vec<NodeMapEntry>,
     ghost_starts: Vec<u16>,
 }
 
-fn parse(input: &str) -> Result<ParseOutput> {
+fn parse(input: &str) -> Result<ParseOutput<'_>> {
     let mut it = input.lines();
     let directions_input = it.next().unwrap();
     let directions: Vec<Direction> = directions_input
@@ -151,19 +168,6 @@ pub fn parse_node(line: &str) -> IResult<&str, (&str, &str, &str)> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::runner::test::{input_test, sample_test};
-
-    sample_test!(sample_part1, 8, Some(6), None);
-    input_test!(part1, 8, Some(15517), None);
-    input_test!(part2, 8, None, Some(14935034899483));
-
-    #[test]
-    fn sample_part2() -> color_eyre::Result<()> {
-        let input = std::fs::read_to_string("inputs/8/sample_part2.txt")?;
-        let parsed = parse(&input)?;
-        assert_eq!(6, super::part2(&parsed)?);
-        Ok(())
-    }
 
     #[test]
     fn parse_node_line() {
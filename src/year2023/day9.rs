@@ -0,0 +1,62 @@
+use color_eyre::{eyre::anyhow, Result};
+use nom::{bytes::complete::tag, character::complete::i32, multi::separated_list1, IResult};
+use rayon::{
+    iter::{IntoParallelRefIterator, ParallelIterator},
+    str::ParallelString,
+};
+
+use crate::answers::Answer;
+use crate::solution::Solution;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+        input
+            .par_lines()
+            .map(|l| {
+                let (_, v) = parse_line(l).map_err(|e| anyhow!("Parse error: {}", e))?;
+                Ok(v)
+            })
+            .collect()
+    }
+
+    fn part1(sequences: &Vec<Vec<i32>>) -> Result<Answer> {
+        let sum: i32 = sequences.par_iter().map(|v| find_next(v.iter())).sum();
+        Ok(sum.into())
+    }
+
+    fn part2(sequences: &Vec<Vec<i32>>) -> Result<Answer> {
+        let sum: i32 = sequences
+            .par_iter()
+            .map(|v| find_next(v.iter().rev()))
+            .sum();
+        Ok(sum.into())
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(tag(" "), i32)(input)
+}
+
+fn find_next<'a>(seq: impl Iterator<Item = &'a i32>) -> i32 {
+    let mut v = Vec::with_capacity(20);
+    for &n in seq {
+        if v.is_empty() {
+            v.push(n);
+            continue;
+        }
+        let mut last_value = v[0];
+        v[0] = n;
+        for i in 1..v.len() {
+            (last_value, v[i]) = (v[i], v[i - 1] - last_value);
+        }
+        let diff = v[v.len() - 1] - last_value;
+        if diff != 0 {
+            v.push(diff);
+        }
+    }
+    v.iter().sum()
+}